@@ -18,6 +18,9 @@ pub(crate) struct Cli {
     /// Show parse errors.
     #[arg(long, global = true, default_value = "false")]
     pub parsing_errors: bool,
+    /// Number of files to lint in parallel. Defaults to the number of CPUs.
+    #[arg(long, global = true)]
+    pub threads: Option<usize>,
 }
 
 #[derive(Debug, Subcommand)]
@@ -32,6 +35,20 @@ pub(crate) enum Commands {
         about = "Fix SQL files via passing a list of files or using stdin"
     )]
     Fix(FixArgs),
+    #[command(
+        name = "format",
+        about = "Format SQL files by only applying the layout and capitalisation rules"
+    )]
+    Format(FormatArgs),
+    #[command(name = "parse", about = "Parse a SQL file and print its parse tree")]
+    Parse(ParseArgs),
+    #[command(
+        name = "rules",
+        about = "List the available rules and their configuration"
+    )]
+    Rules(RulesArgs),
+    #[command(name = "dialects", about = "List the available dialects")]
+    Dialects,
     #[command(name = "lsp", about = "Run an LSP server")]
     Lsp,
     #[command(
@@ -47,6 +64,24 @@ pub(crate) struct LintArgs {
     pub paths: Vec<PathBuf>,
     #[arg(default_value_t, short, long)]
     pub format: Format,
+    /// Stop reporting violations once this many have been reported. Files
+    /// are reported in path order, so the same violations make the cut on
+    /// every run. Every file is still linted, and counts towards the exit
+    /// code.
+    #[arg(long)]
+    pub max_violations: Option<usize>,
+    /// When reading from stdin, the path the SQL is reported under and whose
+    /// directory the config is loaded from, unless `--config` is given.
+    #[arg(long)]
+    pub stdin_filename: Option<PathBuf>,
+    /// Lint every file, instead of skipping the files which passed the last
+    /// time and haven't changed since. Only files rendered by the raw
+    /// templater are ever skipped.
+    #[arg(long)]
+    pub no_cache: bool,
+    /// Directory in which to keep track of the files which passed.
+    #[arg(long, default_value = ".sqruff_cache")]
+    pub cache_dir: PathBuf,
 }
 
 #[derive(Debug, Parser)]
@@ -58,6 +93,57 @@ pub(crate) struct FixArgs {
     pub force: bool,
     #[arg(default_value_t, short, long)]
     pub format: Format,
+    /// Print the fixed SQL to stdout instead of writing the files, with a
+    /// `-- <path>` header before each file when there are several.
+    #[arg(long, conflicts_with = "diff")]
+    pub stdout: bool,
+    /// Print a unified diff of the fixes instead of writing the files, and
+    /// fail if there is anything to fix.
+    #[arg(long)]
+    pub diff: bool,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct FormatArgs {
+    /// Files or directories to format. Use `-` to read from stdin.
+    pub paths: Vec<PathBuf>,
+    /// Don't write the files, and fail if any of them would be reformatted.
+    #[arg(long)]
+    pub check: bool,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct ParseArgs {
+    /// File to parse. Use `-` to read from stdin.
+    pub path: PathBuf,
+    #[arg(default_value_t, short, long)]
+    pub format: ParseFormat,
+    /// Print the tree even if parts of the SQL can't be parsed, which then
+    /// show up as `unparsable` segments.
+    #[arg(long)]
+    pub include_unparsable: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum, Display)]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum ParseFormat {
+    #[default]
+    Yaml,
+    Json,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct RulesArgs {
+    #[arg(default_value_t, short, long)]
+    pub format: RulesFormat,
+}
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum, Display)]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum RulesFormat {
+    #[default]
+    Human,
+    Json,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum, Display)]
@@ -66,6 +152,8 @@ pub(crate) enum Format {
     Human,
     GithubAnnotationNative,
     Json,
+    Sarif,
+    Gitlab,
 }
 
 impl Default for Format {
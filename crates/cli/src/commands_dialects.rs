@@ -0,0 +1,25 @@
+use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::registry::DialectRegistry;
+use sqruff_lib_dialects::is_dialect_enabled;
+use strum::IntoEnumIterator;
+
+pub(crate) fn dialects() {
+    let mut dialects: Vec<(String, &str)> = DialectKind::iter()
+        .filter(is_dialect_enabled)
+        .map(|kind| (kind.as_ref().to_string(), kind.description()))
+        .collect();
+    dialects.extend(
+        DialectRegistry::names()
+            .into_iter()
+            .map(|name| (name, "Registered by a plugin.")),
+    );
+
+    let width = dialects
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    for (name, description) in dialects {
+        println!("{name:<width$}  {description}");
+    }
+}
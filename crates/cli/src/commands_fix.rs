@@ -1,8 +1,9 @@
 use crate::check_user_input;
 use crate::commands::FixArgs;
-use crate::commands::Format;
 use crate::linter;
+use similar::TextDiff;
 use sqruff_lib::core::config::FluffConfig;
+use sqruff_lib::core::linter::linting_result::LintingResult;
 use std::path::Path;
 
 pub(crate) fn run_fix(
@@ -15,10 +16,16 @@ pub(crate) fn run_fix(
         paths,
         force,
         format,
+        stdout,
+        diff,
     } = args;
     let mut linter = linter(config, format, collect_parse_errors);
     let result = linter.lint_paths(paths, true, &ignorer);
 
+    if stdout || diff {
+        return emit_fixes(result, diff);
+    }
+
     if result
         .paths
         .iter()
@@ -69,19 +76,22 @@ pub(crate) fn run_fix(
     }
 }
 
-pub(crate) fn run_fix_stdin(
-    config: FluffConfig,
-    format: Format,
-    collect_parse_errors: bool,
-) -> i32 {
+pub(crate) fn run_fix_stdin(args: FixArgs, config: FluffConfig, collect_parse_errors: bool) -> i32 {
     let read_in = crate::stdin::read_std_in().unwrap();
 
-    let linter = linter(config, format, collect_parse_errors);
+    let linter = linter(config, args.format, collect_parse_errors);
     let result = linter.lint_string(&read_in, None, true);
 
-    // print fixed to std out
     let violations = result.get_violations(Some(false));
-    println!("{}", result.fix_string());
+    let fixed = result.fix_string();
+
+    if args.diff {
+        print!("{}", unified_diff("<stdin>", &read_in, &fixed));
+        return i32::from(!violations.is_empty() || fixed != read_in);
+    }
+
+    // print fixed to std out
+    println!("{}", fixed);
 
     // if all fixable violations are fixable, return 0 else return 1
     if violations.is_empty() {
@@ -90,3 +100,50 @@ pub(crate) fn run_fix_stdin(
         1
     }
 }
+
+/// Prints the fixed files, or a diff of the fixes with `diff`, instead of
+/// writing them back. Like `rustfmt --check`, pending fixes fail the diff.
+/// When several files are printed, each is preceded by a `-- <path>` header.
+fn emit_fixes(result: LintingResult, diff: bool) -> i32 {
+    let mut any_unfixable_errors = false;
+    let mut any_changes = false;
+
+    let mut files = result
+        .paths
+        .into_iter()
+        .flat_map(|linted_dir| linted_dir.files)
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    let with_headers = !diff && files.len() > 1;
+
+    for mut file in files {
+        any_unfixable_errors |= !file.get_violations(Some(false)).is_empty();
+
+        let path = std::mem::take(&mut file.path);
+        let source = file.templated_file.source_str.clone();
+        let fixed = file.fix_string();
+
+        if diff {
+            any_changes |= fixed != source;
+            print!("{}", unified_diff(&path, &source, &fixed));
+        } else {
+            if with_headers {
+                println!("-- {path}");
+            }
+            print!("{}", fixed);
+        }
+    }
+
+    i32::from(any_unfixable_errors || any_changes)
+}
+
+fn unified_diff(path: &str, source: &str, fixed: &str) -> String {
+    if source == fixed {
+        return String::new();
+    }
+
+    TextDiff::from_lines(source, fixed)
+        .unified_diff()
+        .header(path, path)
+        .to_string()
+}
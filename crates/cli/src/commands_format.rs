@@ -0,0 +1,84 @@
+use crate::commands::FormatArgs;
+use sqruff_lib::core::config::FluffConfig;
+use sqruff_lib::core::linter::core::Linter;
+use std::path::Path;
+
+/// The rules applied by `sqruff format`, out of those selected by the config.
+/// They only change the layout and capitalisation of the SQL, never what it
+/// means.
+const FORMAT_RULES: &[&str] = &["capitalisation", "layout"];
+
+fn format_linter(mut config: FluffConfig, collect_parse_errors: bool) -> Linter {
+    config.restrict_rules(FORMAT_RULES);
+    Linter::new(config, None, None, collect_parse_errors)
+}
+
+pub(crate) fn run_format(
+    args: FormatArgs,
+    config: FluffConfig,
+    ignorer: impl Fn(&Path) -> bool + Send + Sync,
+    collect_parse_errors: bool,
+) -> i32 {
+    let FormatArgs { paths, check } = args;
+    let mut linter = format_linter(config, collect_parse_errors);
+    let result = linter.lint_paths(paths, true, &ignorer);
+
+    let mut count_files = 0;
+    let mut count_reformatted = 0;
+    let mut count_failed = 0;
+    for linted_dir in result.paths {
+        for mut file in linted_dir.files {
+            count_files += 1;
+
+            let path = std::mem::take(&mut file.path);
+            let source = file.templated_file.source_str.clone();
+            let formatted = file.fix_string();
+            if formatted == source {
+                continue;
+            }
+
+            if check {
+                eprintln!("Would reformat: {path}");
+            } else if let Err(err) = std::fs::write(&path, formatted) {
+                eprintln!("Failed to reformat {path}: {err}");
+                count_failed += 1;
+                continue;
+            }
+            count_reformatted += 1;
+        }
+    }
+
+    let count_unchanged = count_files - count_reformatted - count_failed;
+    if check {
+        eprintln!(
+            "{count_reformatted} file(s) would be reformatted, {count_unchanged} file(s) already formatted."
+        );
+        i32::from(count_reformatted > 0)
+    } else {
+        eprintln!(
+            "{count_reformatted} file(s) reformatted, {count_unchanged} file(s) left unchanged."
+        );
+        if count_failed > 0 {
+            eprintln!("{count_failed} file(s) failed to be reformatted.");
+        }
+        i32::from(count_failed > 0)
+    }
+}
+
+pub(crate) fn run_format_stdin(
+    args: FormatArgs,
+    config: FluffConfig,
+    collect_parse_errors: bool,
+) -> i32 {
+    let read_in = crate::stdin::read_std_in().unwrap();
+
+    let linter = format_linter(config, collect_parse_errors);
+    let formatted = linter.lint_string(&read_in, None, true).fix_string();
+
+    if args.check {
+        return i32::from(formatted != read_in);
+    }
+
+    print!("{formatted}");
+    0
+}
@@ -1,5 +1,5 @@
-use crate::commands::{Format, LintArgs};
-use crate::linter;
+use crate::commands::LintArgs;
+use crate::limited_linter;
 use sqruff_lib::core::config::FluffConfig;
 use std::path::Path;
 
@@ -9,8 +9,18 @@ pub(crate) fn run_lint(
     ignorer: impl Fn(&Path) -> bool + Send + Sync,
     collect_parse_errors: bool,
 ) -> i32 {
-    let LintArgs { paths, format } = args;
-    let mut linter = linter(config, format, collect_parse_errors);
+    let LintArgs {
+        paths,
+        format,
+        max_violations,
+        stdin_filename: _,
+        no_cache,
+        cache_dir,
+    } = args;
+    let mut linter = limited_linter(config, format, max_violations, collect_parse_errors);
+    if !no_cache {
+        linter.enable_cache(cache_dir);
+    }
 
     linter.lint_paths(paths, false, &ignorer);
 
@@ -23,14 +33,24 @@ pub(crate) fn run_lint(
 }
 
 pub(crate) fn run_lint_stdin(
+    args: LintArgs,
     config: FluffConfig,
-    format: Format,
     collect_parse_errors: bool,
 ) -> i32 {
+    let LintArgs {
+        format,
+        max_violations,
+        stdin_filename,
+        ..
+    } = args;
     let read_in = crate::stdin::read_std_in().unwrap();
 
-    let linter = linter(config, format, collect_parse_errors);
-    linter.lint_string(&read_in, None, false);
+    let linter = limited_linter(config, format, max_violations, collect_parse_errors);
+    linter.lint_string(
+        &read_in,
+        stdin_filename.map(|path| path.display().to_string()),
+        false,
+    );
 
     linter.formatter().unwrap().completion_message();
 
@@ -0,0 +1,59 @@
+use crate::commands::{ParseArgs, ParseFormat};
+use sqruff_lib::core::config::FluffConfig;
+use sqruff_lib::core::linter::core::Linter;
+use sqruff_lib_core::parser::segments::base::Tables;
+use std::path::Path;
+
+pub(crate) fn run_parse(args: ParseArgs, config: FluffConfig) -> i32 {
+    let ParseArgs {
+        path,
+        format,
+        include_unparsable,
+    } = args;
+
+    let (sql, filename) = if path == Path::new("-") {
+        (crate::stdin::read_std_in().unwrap(), None)
+    } else {
+        match std::fs::read_to_string(&path) {
+            Ok(sql) => (sql, Some(path.display().to_string())),
+            Err(e) => {
+                eprintln!("Failed to read '{}': {e}", path.display());
+                return 1;
+            }
+        }
+    };
+
+    let linter = Linter::new(config, None, None, true);
+    let tables = Tables::default();
+    let parsed = match linter.parse_string(&tables, &sql, filename) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{e}");
+            return 1;
+        }
+    };
+
+    let has_errors = !parsed.violations.is_empty();
+    for violation in &parsed.violations {
+        eprintln!(
+            "L:{:4} | P:{:4} | {}",
+            violation.line_no, violation.line_pos, violation.description
+        );
+    }
+
+    if has_errors && !include_unparsable {
+        return 1;
+    }
+
+    if let Some(tree) = parsed.tree {
+        match format {
+            ParseFormat::Yaml => print!("{}", tree.stringify(false)),
+            ParseFormat::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(&tree.to_serialised(false, true)).unwrap()
+            ),
+        }
+    }
+
+    i32::from(has_errors)
+}
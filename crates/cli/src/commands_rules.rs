@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use crate::commands::{RulesArgs, RulesFormat};
+use serde_json::json;
+use sqruff_lib::core::config::{FluffConfig, Value};
+
+pub(crate) fn rules(args: RulesArgs, config: &FluffConfig) {
+    let rules = sqruff_lib::rules::rules();
+
+    match args.format {
+        RulesFormat::Human => {
+            for rule in rules {
+                let groups: Vec<_> = rule.groups().iter().map(|group| group.as_ref()).collect();
+
+                println!("{}: {}", rule.code(), rule.name());
+                println!("    {}", rule.description());
+                println!("    Groups: {}", groups.join(", "));
+                println!(
+                    "    Fixable: {}",
+                    if rule.is_fix_compatible() {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                );
+
+                let options = options(config, rule.config_ref());
+                if !options.is_empty() {
+                    println!("    Config:");
+                    for (key, value) in options {
+                        println!("        {key} = {value}");
+                    }
+                }
+            }
+        }
+        RulesFormat::Json => {
+            let rules: Vec<_> = rules
+                .iter()
+                .map(|rule| {
+                    let groups: Vec<_> = rule.groups().iter().map(|group| group.as_ref()).collect();
+
+                    json!({
+                        "code": rule.code(),
+                        "name": rule.name(),
+                        "description": rule.description(),
+                        "groups": groups,
+                        "fixable": rule.is_fix_compatible(),
+                        "config": options(config, rule.config_ref()),
+                    })
+                })
+                .collect();
+
+            println!("{}", serde_json::to_string_pretty(&rules).unwrap());
+        }
+    }
+}
+
+/// The options of a rule with their configured values, in a stable order.
+fn options<'a>(config: &'a FluffConfig, config_ref: &str) -> BTreeMap<&'a str, &'a Value> {
+    config
+        .get_section("rules")
+        .get(config_ref)
+        .and_then(Value::as_map)
+        .map(|options| {
+            options
+                .iter()
+                .map(|(key, value)| (key.as_str(), value))
+                .collect()
+        })
+        .unwrap_or_default()
+}
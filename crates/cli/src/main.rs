@@ -1,7 +1,10 @@
 use clap::Parser as _;
 use commands::Format;
 use sqruff_lib::cli::formatters::Formatter;
+use sqruff_lib::cli::gitlab::GitlabFormatter;
 use sqruff_lib::cli::json::JsonFormatter;
+use sqruff_lib::cli::max_violations::MaxViolationsFormatter;
+use sqruff_lib::cli::sarif::SarifFormatter;
 use sqruff_lib::cli::{
     formatters::OutputStreamFormatter,
     github_annotation_native_formatter::GithubAnnotationNativeFormatter,
@@ -17,9 +20,13 @@ use crate::commands::{Cli, Commands};
 use crate::docs::codegen_docs;
 
 mod commands;
+mod commands_dialects;
 mod commands_fix;
+mod commands_format;
 mod commands_info;
 mod commands_lint;
+mod commands_parse;
+mod commands_rules;
 #[cfg(feature = "codegen-docs")]
 mod docs;
 mod github_action;
@@ -50,6 +57,14 @@ fn main() {
     let cli = Cli::parse();
     let collect_parse_errors = cli.parsing_errors;
 
+    // Files are linted in parallel on the global rayon pool.
+    if let Some(threads) = cli.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .unwrap();
+    }
+
     let config: FluffConfig = if let Some(config) = cli.config.as_ref() {
         if !Path::new(config).is_file() {
             eprintln!(
@@ -80,7 +95,23 @@ fn main() {
                 1
             }
             Ok(false) => commands_lint::run_lint(args, config, ignorer, collect_parse_errors),
-            Ok(true) => commands_lint::run_lint_stdin(config, args.format, collect_parse_errors),
+            Ok(true) => {
+                // Without an explicit config, pick up the config that would
+                // apply to the file if it were linted from disk.
+                let config = match &args.stdin_filename {
+                    Some(stdin_filename) if cli.config.is_none() => {
+                        match FluffConfig::from_path(stdin_filename) {
+                            Ok(config) => config,
+                            Err(e) => {
+                                eprintln!("{e}");
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                    _ => config,
+                };
+                commands_lint::run_lint_stdin(args, config, collect_parse_errors)
+            }
         },
         Commands::Fix(args) => match is_std_in_flag_input(&args.paths) {
             Err(e) => {
@@ -88,8 +119,25 @@ fn main() {
                 1
             }
             Ok(false) => commands_fix::run_fix(args, config, ignorer, collect_parse_errors),
-            Ok(true) => commands_fix::run_fix_stdin(config, args.format, collect_parse_errors),
+            Ok(true) => commands_fix::run_fix_stdin(args, config, collect_parse_errors),
         },
+        Commands::Format(args) => match is_std_in_flag_input(&args.paths) {
+            Err(e) => {
+                eprintln!("{e}");
+                1
+            }
+            Ok(false) => commands_format::run_format(args, config, ignorer, collect_parse_errors),
+            Ok(true) => commands_format::run_format_stdin(args, config, collect_parse_errors),
+        },
+        Commands::Parse(args) => commands_parse::run_parse(args, config),
+        Commands::Rules(args) => {
+            commands_rules::rules(args, &config);
+            0
+        }
+        Commands::Dialects => {
+            commands_dialects::dialects();
+            0
+        }
         Commands::Lsp => {
             sqruff_lsp::run();
             0
@@ -104,6 +152,16 @@ fn main() {
 }
 
 pub(crate) fn linter(config: FluffConfig, format: Format, collect_parse_errors: bool) -> Linter {
+    limited_linter(config, format, None, collect_parse_errors)
+}
+
+/// Build a linter whose output stops after `max_violations` violations.
+pub(crate) fn limited_linter(
+    config: FluffConfig,
+    format: Format,
+    max_violations: Option<usize>,
+    collect_parse_errors: bool,
+) -> Linter {
     let formatter: Arc<dyn Formatter> = match format {
         Format::Human => {
             let output_stream = std::io::stderr().into();
@@ -123,6 +181,18 @@ pub(crate) fn linter(config: FluffConfig, format: Format, collect_parse_errors:
             let formatter = JsonFormatter::default();
             Arc::new(formatter)
         }
+        Format::Sarif => {
+            let formatter = SarifFormatter::default();
+            Arc::new(formatter)
+        }
+        Format::Gitlab => {
+            let formatter = GitlabFormatter::default();
+            Arc::new(formatter)
+        }
+    };
+    let formatter = match max_violations {
+        Some(max_violations) => Arc::new(MaxViolationsFormatter::new(formatter, max_violations)),
+        None => formatter,
     };
 
     Linter::new(config, Some(formatter), None, collect_parse_errors)
@@ -108,4 +108,80 @@ fn fix_return_code() {
     assert_eq!(stdout_str, "SELECT foo AS bar, * FROM tabs\n");
     assert_eq!(stderr_str, "== [<string>] FAIL\nL:   1 | P:   1 | AM04 | Outermost query should produce known number of columns.\n                       | [ambiguous.column_count]\nL:   1 | P:  12 | AL02 | Implicit/explicit aliasing of columns.\n                       | [aliasing.column]\n");
     assert_eq!(output.status.code().unwrap(), 1);
+
+    // STDIN - diff of the fixes fails like `rustfmt --check`
+    let config_file = cargo_folder.join("tests/fix_return_code/fix_everything.cfg");
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("fix")
+        .arg("-f")
+        .arg("human")
+        .arg("--config")
+        .arg(&config_file)
+        .arg("--diff")
+        .arg("-");
+    cmd.write_stdin("SELECT foo bar FROM tabs\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    assert!(stdout_str.starts_with("--- <stdin>\n+++ <stdin>\n"));
+    assert!(stdout_str.contains("-SELECT foo bar FROM tabs\n+SELECT foo AS bar FROM tabs\n"));
+    assert_eq!(output.status.code().unwrap(), 1);
+
+    // File - stdout prints the fixed SQL and leaves the file untouched
+    let dir = tempfile::tempdir().unwrap();
+    let sql_file = dir.path().join("query.sql");
+    std::fs::write(&sql_file, "SELECT foo bar FROM tabs\n").unwrap();
+
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("fix")
+        .arg("-f")
+        .arg("human")
+        .arg("--config")
+        .arg(&config_file)
+        .arg("--stdout")
+        .arg(&sql_file);
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    assert_eq!(stdout_str, "SELECT foo AS bar FROM tabs\n");
+    assert_eq!(
+        std::fs::read_to_string(&sql_file).unwrap(),
+        "SELECT foo bar FROM tabs\n"
+    );
+    assert_eq!(output.status.code().unwrap(), 0);
+
+    // Files - stdout tells several files apart with a header
+    let other_file = dir.path().join("other.sql");
+    std::fs::write(&other_file, "SELECT baz qux FROM tabs\n").unwrap();
+
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("fix")
+        .arg("-f")
+        .arg("human")
+        .arg("--config")
+        .arg(&config_file)
+        .arg("--stdout")
+        .arg(&sql_file)
+        .arg(&other_file);
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    assert_eq!(
+        stdout_str,
+        format!(
+            "-- {}\nSELECT baz AS qux FROM tabs\n-- {}\nSELECT foo AS bar FROM tabs\n",
+            other_file.display(),
+            sql_file.display()
+        )
+    );
+    assert_eq!(output.status.code().unwrap(), 0);
 }
@@ -0,0 +1,125 @@
+use core::str;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+
+fn main() {
+    format();
+}
+
+fn format() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = PathBuf::from(cargo_folder);
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    // STDIN - the spacing is fixed but the implicit alias (AL02) is left alone
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("format").arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("select foo  bar from tabs\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    assert_eq!(stdout_str, "select foo bar from tabs\n");
+    assert_eq!(output.status.code().unwrap(), 0);
+
+    // STDIN - check fails without printing when something would be reformatted
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("format").arg("--check").arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("select foo  bar from tabs\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    assert!(output.stdout.is_empty());
+    assert_eq!(output.status.code().unwrap(), 1);
+
+    // File - check leaves the file untouched, format rewrites it
+    let dir = tempfile::tempdir().unwrap();
+    let sql_file = dir.path().join("query.sql");
+    std::fs::write(&sql_file, "select foo  bar from tabs\n").unwrap();
+
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("format").arg("--check").arg(&sql_file);
+    cmd.current_dir(cargo_folder);
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    assert!(stderr_str.contains("Would reformat:"));
+    assert_eq!(
+        std::fs::read_to_string(&sql_file).unwrap(),
+        "select foo  bar from tabs\n"
+    );
+    assert_eq!(output.status.code().unwrap(), 1);
+
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("format").arg(&sql_file);
+    cmd.current_dir(cargo_folder);
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    assert_eq!(
+        std::fs::read_to_string(&sql_file).unwrap(),
+        "select foo bar from tabs\n"
+    );
+    assert_eq!(output.status.code().unwrap(), 0);
+
+    // Config - rules excluded in the config are not applied
+    let config_file = dir.path().join(".sqruff");
+    std::fs::write(&config_file, "[sqruff]\nexclude_rules = LT01\n").unwrap();
+    std::fs::write(&sql_file, "select foo  bar from tabs\n").unwrap();
+
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("format")
+        .arg("--config")
+        .arg(&config_file)
+        .arg(&sql_file);
+    cmd.current_dir(cargo_folder);
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    assert_eq!(
+        std::fs::read_to_string(&sql_file).unwrap(),
+        "select foo  bar from tabs\n"
+    );
+    assert_eq!(output.status.code().unwrap(), 0);
+
+    // Config - only the selected rules are applied
+    std::fs::write(&config_file, "[sqruff]\nrules = LT01\n").unwrap();
+    std::fs::write(&sql_file, "SELECT foo  bar from tabs\n").unwrap();
+
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("format")
+        .arg("--config")
+        .arg(&config_file)
+        .arg(&sql_file);
+    cmd.current_dir(cargo_folder);
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    assert_eq!(
+        std::fs::read_to_string(&sql_file).unwrap(),
+        "SELECT foo bar from tabs\n"
+    );
+    assert_eq!(output.status.code().unwrap(), 0);
+}
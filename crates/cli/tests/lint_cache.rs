@@ -0,0 +1,63 @@
+use core::str;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+
+fn main() {
+    lint_cache();
+}
+
+fn lint(sqruff_path: &Path, dir: &Path, args: &[&str]) -> (String, i32) {
+    let mut cmd = Command::new(sqruff_path);
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("lint").args(args).arg("query.sql");
+    cmd.current_dir(dir);
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    (
+        str::from_utf8(&output.stderr).unwrap().to_string(),
+        output.status.code().unwrap(),
+    )
+}
+
+fn lint_cache() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = PathBuf::from(cargo_folder);
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("query.sql"), "SELECT a FROM t\n").unwrap();
+
+    // The cache is on by default and records the file which passed
+    let (first, code) = lint(&sqruff_path, dir.path(), &[]);
+    assert_eq!(code, 0);
+    let entries = std::fs::read_to_string(dir.path().join(".sqruff_cache/lint")).unwrap();
+    assert_eq!(entries.lines().count(), 1);
+
+    // A cached file is reported the same way as when it is linted
+    let (second, code) = lint(&sqruff_path, dir.path(), &[]);
+    assert_eq!(code, 0);
+    assert_eq!(first, second);
+
+    // --cache-dir moves the cache
+    let (_, code) = lint(&sqruff_path, dir.path(), &["--cache-dir", "elsewhere"]);
+    assert_eq!(code, 0);
+    assert!(dir.path().join("elsewhere/lint").exists());
+
+    // --no-cache neither reads nor writes the cache
+    let (_, code) = lint(
+        &sqruff_path,
+        dir.path(),
+        &["--no-cache", "--cache-dir", "disabled"],
+    );
+    assert_eq!(code, 0);
+    assert!(!dir.path().join("disabled").exists());
+}
@@ -0,0 +1,86 @@
+use core::str;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+
+fn main() {
+    max_violations();
+}
+
+fn max_violations() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = PathBuf::from(cargo_folder);
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    // STDIN - output stops after the limit but the exit code still fails
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("lint")
+        .arg("-f")
+        .arg("human")
+        .arg("--max-violations")
+        .arg("2")
+        .arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("SELECT a+b  ,c FROM t\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    assert_eq!(
+        stderr_str,
+        "== [<string>] FAIL\nL:   1 | P:   8 | AL03 | Column expression without alias. Use explicit `AS`\n                       | clause. [aliasing.expression]\nL:   1 | P:   9 | LT01 | Expected single whitespace between \"a\" and \"+\".\n                       | [layout.spacing]\nThe linter processed 1 file(s).\nAll Finished\nOutput truncated after 2 violation(s), leaving out violations in 1 file(s).\n"
+    );
+    assert_eq!(output.status.code().unwrap(), 1);
+
+    // STDIN - no note when the limit is not reached
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("lint")
+        .arg("-f")
+        .arg("human")
+        .arg("--max-violations")
+        .arg("10")
+        .arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("SELECT a+b FROM t\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    assert!(!stderr_str.contains("Output truncated"));
+    assert_eq!(stderr_str.matches("L:").count(), 2);
+    assert_eq!(output.status.code().unwrap(), 1);
+
+    // Files - the limit applies across the whole run, in path order
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("lint")
+        .arg("-f")
+        .arg("human")
+        .arg("--max-violations")
+        .arg("1")
+        .arg(cargo_folder.join("tests/max_violations"));
+    cmd.current_dir(cargo_folder);
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    assert_eq!(stderr_str.matches("L:").count(), 1);
+    assert!(stderr_str.contains("first.sql] FAIL"));
+    assert!(!stderr_str.contains("second.sql"));
+    assert!(stderr_str.contains("The linter processed 2 file(s).\n"));
+    assert!(stderr_str.ends_with(
+        "Output truncated after 1 violation(s), leaving out violations in 2 file(s).\n"
+    ));
+    assert_eq!(output.status.code().unwrap(), 1);
+}
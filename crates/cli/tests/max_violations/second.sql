@@ -0,0 +1 @@
+SELECT c  ,d FROM u
@@ -0,0 +1,77 @@
+use core::str;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+
+fn main() {
+    parse();
+}
+
+fn parse() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = PathBuf::from(cargo_folder);
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    // STDIN - YAML by default
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("parse").arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("SELECT 1\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    assert!(stdout_str.starts_with("file:\n"));
+    assert!(stdout_str.contains("select_statement:"));
+    assert_eq!(output.status.code().unwrap(), 0);
+
+    // STDIN - JSON
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("parse").arg("-f").arg("json").arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("SELECT 1\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let tree: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(tree["file"].is_object());
+    assert_eq!(output.status.code().unwrap(), 0);
+
+    // STDIN - unparsable SQL only prints the tree when asked to
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("parse").arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("SELECT 1)\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    assert!(output.stdout.is_empty());
+    assert!(stderr_str.contains("Unparsable section"));
+    assert_eq!(output.status.code().unwrap(), 1);
+
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("parse").arg("--include-unparsable").arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("SELECT 1)\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stdout_str = str::from_utf8(&output.stdout).unwrap();
+    assert!(stdout_str.contains("unparsable:"));
+    assert_eq!(output.status.code().unwrap(), 1);
+}
@@ -0,0 +1,44 @@
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+
+fn main() {
+    rules();
+}
+
+fn rules() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = PathBuf::from(cargo_folder);
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("rules").arg("-f").arg("json");
+    cmd.current_dir(cargo_folder);
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+    assert_eq!(output.status.code().unwrap(), 0);
+
+    let rules: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let rule = rules
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rule| rule["code"] == "CP01")
+        .unwrap();
+
+    assert_eq!(rule["name"], "capitalisation.keywords");
+    assert_eq!(rule["fixable"], true);
+    assert!(rule["groups"]
+        .as_array()
+        .unwrap()
+        .contains(&"capitalisation".into()));
+    assert_eq!(rule["config"]["capitalisation_policy"], "consistent");
+}
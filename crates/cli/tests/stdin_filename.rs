@@ -0,0 +1,64 @@
+use core::str;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+
+fn main() {
+    stdin_filename();
+}
+
+fn stdin_filename() {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let cargo_folder = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut sqruff_path = PathBuf::from(cargo_folder);
+    sqruff_path.push(format!("../../target/{}/sqruff", profile));
+
+    // The filename is reported and the config next to it is used, which only
+    // enables LT01.
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("lint")
+        .arg("-f")
+        .arg("human")
+        .arg("--stdin-filename")
+        .arg("tests/stdin_filename/query.sql")
+        .arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("SELECT a+b  ,c FROM t\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    assert!(stderr_str.starts_with("== [tests/stdin_filename/query.sql] FAIL\n"));
+    assert!(stderr_str.contains("LT01"));
+    assert!(!stderr_str.contains("AL03"));
+    assert_eq!(output.status.code().unwrap(), 1);
+
+    // An explicit config takes precedence over the one next to the file.
+    let mut cmd = Command::new(sqruff_path.clone());
+    cmd.env("HOME", PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    cmd.arg("--config")
+        .arg("tests/configure_rule/config_core.cfg")
+        .arg("lint")
+        .arg("-f")
+        .arg("human")
+        .arg("--stdin-filename")
+        .arg("tests/stdin_filename/query.sql")
+        .arg("-");
+    cmd.current_dir(cargo_folder);
+    cmd.write_stdin("SELECT a+b  ,c FROM t\n");
+
+    let assert = cmd.assert();
+    let output = assert.get_output();
+
+    let stderr_str = str::from_utf8(&output.stderr).unwrap();
+    assert!(stderr_str.starts_with("== [tests/stdin_filename/query.sql] FAIL\n"));
+    assert!(stderr_str.contains("AL03"));
+    assert_eq!(output.status.code().unwrap(), 1);
+}
@@ -1,4 +1,5 @@
 pub mod base;
 pub mod common;
 pub mod init;
+pub mod registry;
 pub mod syntax;
@@ -20,6 +20,7 @@ pub struct Dialect {
     library: AHashMap<Cow<'static, str>, DialectElementType>,
     sets: AHashMap<&'static str, AHashSet<&'static str>>,
     pub bracket_collections: AHashMap<&'static str, AHashSet<BracketPair>>,
+    function_arities: AHashMap<&'static str, FunctionArity>,
     lexer: Option<Lexer>,
 }
 
@@ -202,6 +203,16 @@ impl Dialect {
         }
     }
 
+    /// The number of arguments a built-in function accepts, if known. The
+    /// name is expected to be upper case.
+    pub fn function_arity(&self, name: &str) -> Option<FunctionArity> {
+        self.function_arities.get(name).copied()
+    }
+
+    pub fn update_function_arities(&mut self, arities: Vec<(&'static str, FunctionArity)>) {
+        self.function_arities.extend(arities);
+    }
+
     pub fn r#ref(&self, name: &str) -> Matchable {
         match self.library.get(name) {
             Some(DialectElementType::Matchable(matchable)) => matchable.clone(),
@@ -264,3 +275,6 @@ impl Dialect {
 }
 
 pub type BracketPair = (&'static str, &'static str, &'static str, bool);
+
+/// The minimum and (optional) maximum number of arguments of a function.
+pub type FunctionArity = (usize, Option<usize>);
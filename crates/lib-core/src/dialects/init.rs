@@ -24,12 +24,36 @@ pub enum DialectKind {
     Clickhouse,
     Databricks,
     Duckdb,
+    Mysql,
     Postgres,
     Redshift,
     Snowflake,
     Sparksql,
     Sqlite,
     Trino,
+    Tsql,
+}
+
+impl DialectKind {
+    /// A short description of the dialect, for listing the dialects to users.
+    pub fn description(&self) -> &'static str {
+        match self {
+            DialectKind::Ansi => "Standard SQL, which the other dialects build on.",
+            DialectKind::Athena => "Amazon Athena.",
+            DialectKind::Bigquery => "Google BigQuery.",
+            DialectKind::Clickhouse => "ClickHouse.",
+            DialectKind::Databricks => "Databricks SQL, an extension of Spark SQL.",
+            DialectKind::Duckdb => "DuckDB.",
+            DialectKind::Mysql => "MySQL.",
+            DialectKind::Postgres => "PostgreSQL.",
+            DialectKind::Redshift => "Amazon Redshift, an extension of PostgreSQL.",
+            DialectKind::Snowflake => "Snowflake.",
+            DialectKind::Sparksql => "Apache Spark SQL.",
+            DialectKind::Sqlite => "SQLite.",
+            DialectKind::Trino => "Trino, formerly PrestoSQL.",
+            DialectKind::Tsql => "Transact-SQL, as used by Microsoft SQL Server and Azure Synapse.",
+        }
+    }
 }
 
 /// Generate a readout of available dialects.
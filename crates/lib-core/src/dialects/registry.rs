@@ -0,0 +1,84 @@
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
+
+use ahash::AHashMap;
+
+use crate::dialects::base::Dialect;
+use crate::dialects::init::DialectKind;
+use crate::errors::SQLFluffUserError;
+
+/// Builds an expanded dialect, like the `dialect()` function of each built-in
+/// dialect.
+pub type DialectConstructor = fn() -> Dialect;
+
+/// Dialects defined outside of sqruff, resolvable by name from the `dialect`
+/// config option just like the compiled-in ones.
+///
+/// A registered dialect is usually derived from one of the built-in dialects
+/// and keeps its `name`, which rules use to pick dialect specific behaviour.
+pub struct DialectRegistry;
+
+fn dialects() -> &'static RwLock<AHashMap<String, DialectConstructor>> {
+    static DIALECTS: OnceLock<RwLock<AHashMap<String, DialectConstructor>>> = OnceLock::new();
+    DIALECTS.get_or_init(Default::default)
+}
+
+impl DialectRegistry {
+    /// Registers a dialect under `name`, replacing any dialect previously
+    /// registered under the same name. Built-in dialects can't be replaced.
+    pub fn register(
+        name: impl Into<String>,
+        constructor: DialectConstructor,
+    ) -> Result<(), SQLFluffUserError> {
+        let name = name.into().to_lowercase();
+        if DialectKind::from_str(&name).is_ok() {
+            return Err(SQLFluffUserError::new(format!(
+                "Cannot register dialect `{name}` as it is a built-in dialect."
+            )));
+        }
+
+        dialects().write().unwrap().insert(name, constructor);
+        Ok(())
+    }
+
+    /// Builds the dialect registered under `name`.
+    pub fn get(name: &str) -> Option<Dialect> {
+        let constructor = *dialects().read().unwrap().get(&name.to_lowercase())?;
+        Some(constructor())
+    }
+
+    pub fn contains(name: &str) -> bool {
+        dialects()
+            .read()
+            .unwrap()
+            .contains_key(&name.to_lowercase())
+    }
+
+    /// The names of the registered dialects, sorted alphabetically.
+    pub fn names() -> Vec<String> {
+        let mut names: Vec<_> = dialects().read().unwrap().keys().cloned().collect();
+        names.sort();
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DialectRegistry;
+    use crate::dialects::base::Dialect;
+
+    #[test]
+    fn registered_dialects_resolve_by_name() {
+        DialectRegistry::register("Acme", Dialect::default).unwrap();
+
+        assert!(DialectRegistry::contains("acme"));
+        assert!(DialectRegistry::get("ACME").is_some());
+        assert!(DialectRegistry::names().contains(&"acme".to_string()));
+        assert!(DialectRegistry::get("unknown").is_none());
+    }
+
+    #[test]
+    fn built_in_dialects_cannot_be_replaced() {
+        assert!(DialectRegistry::register("postgres", Dialect::default).is_err());
+    }
+}
@@ -30,6 +30,7 @@ pub enum SyntaxKind {
     GroupingExpressionList,
     SetClause,
     FetchClause,
+    OffsetClause,
     FunctionDefinition,
     AlterSequenceOptionsSegment,
     RoleReference,
@@ -593,6 +594,16 @@ pub enum SyntaxKind {
     MapTypeSchema,
     PrepareStatement,
     ExecuteStatement,
+    ForSystemTimeClause,
+    PeriodDefinition,
+    IndexHintClause,
+    SquareQuote,
+    BatchSeparator,
+    BeginEndBlock,
+    TableHintClause,
+    PositionalJoinClause,
+    FormatClause,
+    ReplaceStatement,
 }
 
 impl SyntaxKind {
@@ -112,7 +112,7 @@ impl Matcher {
     pub fn legacy(
         name: &'static str,
         starts_with: fn(&str) -> bool,
-        pattern: &'static str,
+        pattern: &str,
         syntax_kind: SyntaxKind,
     ) -> Self {
         Self::new(Pattern::legacy(name, starts_with, pattern, syntax_kind))
@@ -304,7 +304,7 @@ impl Pattern {
     pub fn legacy(
         name: &'static str,
         starts_with: fn(&str) -> bool,
-        regex: &'static str,
+        regex: &str,
         syntax_kind: SyntaxKind,
     ) -> Self {
         let regex = format!("^{}", regex);
@@ -228,6 +228,11 @@ impl TemplatedFileInner {
         })
     }
 
+    /// Get the name of the file the template was read from.
+    pub fn name(&self) -> &str {
+        &self.f_name
+    }
+
     /// Return true if there's a templated file.
     pub fn is_templated(&self) -> bool {
         self.templated_str.is_some()
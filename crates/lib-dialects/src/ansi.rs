@@ -95,6 +95,25 @@ pub fn raw_dialect() -> Dialect {
     //   UNNEST(), as BigQuery. DB2 is not currently supported by SQLFluff.
     ansi_dialect.sets_mut("value_table_functions");
 
+    // Argument counts of built-in functions, as (min, max). Only functions whose
+    // signature is the same across the supported dialects belong here, dialects
+    // can extend or override the list.
+    ansi_dialect.update_function_arities(vec![
+        ("ABS", (1, Some(1))),
+        ("COALESCE", (1, None)),
+        ("EXP", (1, Some(1))),
+        ("LN", (1, Some(1))),
+        ("LOWER", (1, Some(1))),
+        ("LTRIM", (1, Some(2))),
+        ("MOD", (2, Some(2))),
+        ("NULLIF", (2, Some(2))),
+        ("POWER", (2, Some(2))),
+        ("RTRIM", (1, Some(2))),
+        ("SIGN", (1, Some(1))),
+        ("SQRT", (1, Some(1))),
+        ("UPPER", (1, Some(1))),
+    ]);
+
     ansi_dialect.add([
         (
             "ArrayTypeSchemaSegment".into(),
@@ -623,6 +642,18 @@ pub fn raw_dialect() -> Dialect {
             "PreTableFunctionKeywordsGrammar".into(),
             Nothing::new().to_matchable().into(),
         ),
+        (
+            // This is a placeholder for dialects with options between EXPLAIN
+            // and the explained statement.
+            "ExplainOptionsGrammar".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            // This is a placeholder for dialects which allow the explicit
+            // `BY TARGET` on `WHEN NOT MATCHED` in a MERGE.
+            "MergeNotMatchedByTargetGrammar".into(),
+            Nothing::new().to_matchable().into(),
+        ),
         (
             "BinaryOperatorGrammar".into(),
             one_of(vec_of_erased![
@@ -666,9 +697,43 @@ pub fn raw_dialect() -> Dialect {
         ),
         (
             "TemporaryGrammar".into(),
-            one_of(vec![
-                Ref::keyword("TEMP").to_matchable(),
-                Ref::keyword("TEMPORARY").to_matchable(),
+            Sequence::new(vec![
+                one_of(vec![
+                    Ref::keyword("GLOBAL").to_matchable(),
+                    Ref::keyword("LOCAL").to_matchable(),
+                ])
+                .config(|this| this.optional())
+                .to_matchable(),
+                one_of(vec![
+                    Ref::keyword("TEMP").to_matchable(),
+                    Ref::keyword("TEMPORARY").to_matchable(),
+                ])
+                .to_matchable(),
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "OnCommitGrammar".into(),
+            Sequence::new(vec![
+                Ref::keyword("ON").to_matchable(),
+                Ref::keyword("COMMIT").to_matchable(),
+                one_of(vec![
+                    Ref::keyword("DELETE").to_matchable(),
+                    Ref::keyword("PRESERVE").to_matchable(),
+                ])
+                .to_matchable(),
+                Ref::keyword("ROWS").to_matchable(),
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "SchemaElementGrammar".into(),
+            one_of(vec_of_erased![
+                Ref::new("CreateTableStatementSegment"),
+                Ref::new("CreateViewStatementSegment"),
+                Ref::new("AccessStatementSegment")
             ])
             .to_matchable()
             .into(),
@@ -778,6 +843,7 @@ pub fn raw_dialect() -> Dialect {
                 Ref::new("SetOperatorSegment").to_matchable(),
                 Ref::new("WithNoSchemaBindingClauseSegment").to_matchable(),
                 Ref::new("WithDataClauseSegment").to_matchable(),
+                Ref::new("WithCheckOptionSegment").to_matchable(),
                 Ref::keyword("FETCH").to_matchable(),
             ])
             .to_matchable()
@@ -1217,6 +1283,22 @@ pub fn raw_dialect() -> Dialect {
                 ]),
                 // Drop Column
                 Ref::new("AlterTableDropColumnGrammar"),
+                // Rename column
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("RENAME"),
+                    Ref::keyword("COLUMN"),
+                    Ref::new("ColumnReferenceSegment"),
+                    Ref::keyword("TO"),
+                    Ref::new("ColumnReferenceSegment")
+                ]),
+                // Rename constraint
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("RENAME"),
+                    Ref::keyword("CONSTRAINT"),
+                    Ref::new("ObjectReferenceSegment"),
+                    Ref::keyword("TO"),
+                    Ref::new("ObjectReferenceSegment")
+                ]),
                 // Rename
                 Sequence::new(vec_of_erased![
                     Ref::keyword("RENAME"),
@@ -1519,6 +1601,25 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            // OFFSET without LIMIT, optionally followed by a FETCH clause,
+            // e.g. `ORDER BY x OFFSET 10 ROWS FETCH NEXT 5 ROWS ONLY`.
+            "OffsetClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::OffsetClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("OFFSET"),
+                    one_of(vec_of_erased![
+                        Ref::new("NumericLiteralSegment"),
+                        Ref::new("ExpressionSegment"),
+                    ]),
+                    one_of(vec_of_erased![Ref::keyword("ROW"), Ref::keyword("ROWS")]),
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "FunctionDefinitionGrammar".into(),
             NodeMatcher::new(
@@ -1714,6 +1815,64 @@ pub fn raw_dialect() -> Dialect {
                 .to_matchable()
                 .into(),
         ),
+        (
+            // SQL:2011 application-time or system-time period, e.g.
+            // PERIOD FOR SYSTEM_TIME (valid_from, valid_to)
+            "PeriodDefinitionSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::PeriodDefinition,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("PERIOD"),
+                    Ref::keyword("FOR"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("SYSTEM_TIME"),
+                        Ref::new("SingleIdentifierGrammar")
+                    ]),
+                    Bracketed::new(vec_of_erased![
+                        Ref::new("ColumnReferenceSegment"),
+                        Ref::new("CommaSegment"),
+                        Ref::new("ColumnReferenceSegment")
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            // SQL:2011 system-versioned table query, e.g.
+            // FOR SYSTEM_TIME AS OF TIMESTAMP '2024-01-01 00:00:00'
+            "ForSystemTimeClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ForSystemTimeClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("FOR"),
+                    Ref::keyword("SYSTEM_TIME"),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("AS"),
+                            Ref::keyword("OF"),
+                            Ref::new("ExpressionSegment")
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("BETWEEN"),
+                            Ref::new("Expression_B_Grammar"),
+                            Ref::keyword("AND"),
+                            Ref::new("ExpressionSegment")
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("FROM"),
+                            Ref::new("ExpressionSegment"),
+                            Ref::keyword("TO"),
+                            Ref::new("ExpressionSegment")
+                        ])
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "MergeMatchSegment".into(),
             NodeMatcher::new(
@@ -1761,6 +1920,7 @@ pub fn raw_dialect() -> Dialect {
                     Ref::keyword("WHEN"),
                     Ref::keyword("NOT"),
                     Ref::keyword("MATCHED"),
+                    Ref::new("MergeNotMatchedByTargetGrammar").optional(),
                     Sequence::new(vec_of_erased![
                         Ref::keyword("AND"),
                         Ref::new("ExpressionSegment")
@@ -1877,6 +2037,7 @@ pub fn raw_dialect() -> Dialect {
                     Ref::keyword("BY"),
                     one_of(vec_of_erased![
                         Ref::new("CubeRollupClauseSegment"),
+                        Ref::new("GroupingSetsClauseSegment"),
                         Sequence::new(vec_of_erased![
                             MetaSegment::indent(),
                             Delimited::new(vec_of_erased![one_of(vec_of_erased![
@@ -2499,12 +2660,8 @@ pub fn raw_dialect() -> Dialect {
                 SyntaxKind::ExplainStatement,
                 Sequence::new(vec_of_erased![
                     Ref::keyword("EXPLAIN"),
-                    one_of(vec_of_erased![
-                        Ref::new("SelectableGrammar"),
-                        Ref::new("InsertStatementSegment"),
-                        Ref::new("UpdateStatementSegment"),
-                        Ref::new("DeleteStatementSegment")
-                    ])
+                    Ref::new("ExplainOptionsGrammar").optional(),
+                    explainable_stmt(),
                 ])
                 .to_matchable(),
             )
@@ -2715,6 +2872,7 @@ pub fn raw_dialect() -> Dialect {
                     Ref::new("BracketedColumnReferenceListGrammar").optional(),
                     Ref::keyword("AS"),
                     optionally_bracketed(vec_of_erased![Ref::new("SelectableGrammar")]),
+                    Ref::new("WithCheckOptionSegment").optional(),
                     Ref::new("WithNoSchemaBindingClauseSegment").optional()
                 ])
                 .to_matchable(),
@@ -2991,6 +3149,7 @@ pub fn raw_dialect() -> Dialect {
                             Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![one_of(
                                 vec_of_erased![
                                     Ref::new("TableConstraintSegment"),
+                                    Ref::new("PeriodDefinitionSegment"),
                                     Ref::new("ColumnDefinitionSegment")
                                 ]
                             )])]),
@@ -3007,6 +3166,7 @@ pub fn raw_dialect() -> Dialect {
                             Ref::new("TableReferenceSegment")
                         ])
                     ]),
+                    Ref::new("OnCommitGrammar").optional(),
                     Ref::new("TableEndClauseSegment").optional()
                 ])
                 .to_matchable(),
@@ -3884,11 +4044,17 @@ pub fn raw_dialect() -> Dialect {
             "TimeZoneGrammar".into(),
             NodeMatcher::new(
                 SyntaxKind::TimeZoneGrammar,
+                // The zone binds tighter than other operators, so that chained
+                // conversions apply from left to right.
                 AnyNumberOf::new(vec![Sequence::new(vec![
                     Ref::keyword("AT").to_matchable(),
                     Ref::keyword("TIME").to_matchable(),
                     Ref::keyword("ZONE").to_matchable(),
-                    Ref::new("ExpressionSegment").to_matchable(),
+                    one_of(vec![
+                        Ref::new("ShorthandCastSegment").to_matchable(),
+                        Ref::new("Expression_D_Grammar").to_matchable(),
+                    ])
+                    .to_matchable(),
                 ])
                 .to_matchable()])
                 .to_matchable(),
@@ -4167,6 +4333,7 @@ pub fn raw_dialect() -> Dialect {
                     Ref::new("SetOperatorSegment"),
                     Ref::new("WithNoSchemaBindingClauseSegment"),
                     Ref::new("WithDataClauseSegment"),
+                    Ref::new("WithCheckOptionSegment"),
                     Ref::new("OrderByClauseSegment"),
                     Ref::new("LimitClauseSegment")
                 ])
@@ -4224,6 +4391,25 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "WithCheckOptionSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::WithCheckOption,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("WITH"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("CASCADED"),
+                        Ref::keyword("LOCAL")
+                    ])
+                    .config(|this| this.optional()),
+                    Ref::keyword("CHECK"),
+                    Ref::keyword("OPTION"),
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "WithDataClauseSegment".into(),
             NodeMatcher::new(
@@ -4393,6 +4579,7 @@ pub fn raw_dialect() -> Dialect {
                 Sequence::new(vec_of_erased![
                     Ref::new("PreTableFunctionKeywordsGrammar").optional(),
                     optionally_bracketed(vec_of_erased![Ref::new("TableExpressionSegment")]),
+                    Ref::new("ForSystemTimeClauseSegment").optional(),
                     Ref::new("AliasExpressionSegment")
                         .exclude(one_of(vec_of_erased![
                             Ref::new("FromClauseTerminatorGrammar"),
@@ -4416,9 +4603,19 @@ pub fn raw_dialect() -> Dialect {
         ),
         (
             "SelectStatementSegment".into(),
-            NodeMatcher::new(SyntaxKind::SelectStatement, select_statement())
-                .to_matchable()
-                .into(),
+            NodeMatcher::new(
+                SyntaxKind::SelectStatement,
+                select_statement().copy(
+                    Some(vec_of_erased![Ref::new("OffsetClauseSegment").optional()]),
+                    None,
+                    Some(Ref::new("FetchClauseSegment").optional().to_matchable()),
+                    None,
+                    Vec::new(),
+                    false,
+                ),
+            )
+            .to_matchable()
+            .into(),
         ),
         (
             "CreateSchemaStatementSegment".into(),
@@ -4428,7 +4625,15 @@ pub fn raw_dialect() -> Dialect {
                     Ref::keyword("CREATE"),
                     Ref::keyword("SCHEMA"),
                     Ref::new("IfNotExistsGrammar").optional(),
-                    Ref::new("SchemaReferenceSegment")
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            Ref::new("SchemaReferenceSegment").optional(),
+                            Ref::keyword("AUTHORIZATION"),
+                            Ref::new("RoleReferenceSegment")
+                        ]),
+                        Ref::new("SchemaReferenceSegment")
+                    ]),
+                    AnyNumberOf::new(vec_of_erased![Ref::new("SchemaElementGrammar")])
                 ])
                 .to_matchable(),
             )
@@ -5044,6 +5249,7 @@ pub fn get_unordered_select_statement_segment_grammar() -> Matchable {
         Ref::new("SetOperatorSegment"),
         Ref::new("WithNoSchemaBindingClauseSegment"),
         Ref::new("WithDataClauseSegment"),
+        Ref::new("WithCheckOptionSegment"),
         Ref::new("OrderByClauseSegment"),
         Ref::new("LimitClauseSegment")
     ])
@@ -5067,7 +5273,8 @@ pub fn select_statement() -> Matchable {
         vec_of_erased![
             Ref::new("SetOperatorSegment"),
             Ref::new("WithNoSchemaBindingClauseSegment"),
-            Ref::new("WithDataClauseSegment")
+            Ref::new("WithDataClauseSegment"),
+            Ref::new("WithCheckOptionSegment")
         ],
         true,
     )
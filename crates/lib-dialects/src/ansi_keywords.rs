@@ -560,6 +560,7 @@ PERCENT
 PERCENTILE_CONT
 PERCENTILE_DISC
 PERCENT_RANK
+PERIOD
 PIPE
 PLACING
 PLAN
@@ -757,6 +758,7 @@ SYNONYM
 SYSDATE
 SYSID
 SYSTEM
+SYSTEM_TIME
 SYSTEM_USER
 TABLE
 TABLE_NAME
@@ -245,6 +245,23 @@ pub fn dialect() -> Dialect {
             "TrimParametersGrammar".into(),
             Nothing::new().to_matchable().into(),
         ),
+        (
+            "WithCheckOptionSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "ForSystemTimeClauseSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "TemporaryGrammar".into(),
+            one_of(vec_of_erased![
+                Ref::keyword("TEMP"),
+                Ref::keyword("TEMPORARY")
+            ])
+            .to_matchable()
+            .into(),
+        ),
         (
             "ParameterNameSegment".into(),
             one_of(vec_of_erased![
@@ -525,6 +542,18 @@ pub fn dialect() -> Dialect {
         .to_matchable(),
     );
 
+    dialect.replace_grammar(
+        "CreateSchemaStatementSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("CREATE"),
+            Ref::keyword("SCHEMA"),
+            Ref::new("IfNotExistsGrammar").optional(),
+            Ref::new("SchemaReferenceSegment"),
+            Ref::new("OptionsSegment").optional()
+        ])
+        .to_matchable(),
+    );
+
     dialect.add([
         (
             "QualifyClauseSegment".into(),
@@ -1115,6 +1115,53 @@ pub fn dialect() -> Dialect {
         ),
     ]);
 
+    // https://clickhouse.com/docs/en/sql-reference/statements/select/format
+    clickhouse_dialect.add([(
+        "FormatClauseSegment".into(),
+        NodeMatcher::new(
+            SyntaxKind::FormatClause,
+            Sequence::new(vec_of_erased![
+                Ref::keyword("FORMAT"),
+                Ref::new("SingleIdentifierGrammar"),
+            ])
+            .to_matchable(),
+        )
+        .to_matchable()
+        .into(),
+    )]);
+
+    // FORMAT ends whichever clause comes last in the SELECT.
+    for name in [
+        "SelectClauseTerminatorGrammar",
+        "FromClauseTerminatorGrammar",
+        "WhereClauseTerminatorGrammar",
+        "GroupByClauseTerminatorGrammar",
+        "HavingClauseTerminatorGrammar",
+        "OrderByClauseTerminators",
+    ] {
+        let terminators = clickhouse_dialect.grammar(name).copy(
+            Some(vec_of_erased![Ref::keyword("FORMAT")]),
+            None,
+            None,
+            None,
+            Vec::new(),
+            false,
+        );
+        clickhouse_dialect.add([(name.into(), terminators.into())]);
+    }
+
+    clickhouse_dialect.replace_grammar(
+        "SelectStatementSegment",
+        ansi::select_statement().copy(
+            Some(vec_of_erased![Ref::new("FormatClauseSegment").optional()]),
+            None,
+            None,
+            None,
+            Vec::new(),
+            false,
+        ),
+    );
+
     clickhouse_dialect.replace_grammar(
         "StatementSegment",
         ansi::statement_segment().copy(
@@ -2,12 +2,13 @@ use sqruff_lib_core::dialects::base::Dialect;
 use sqruff_lib_core::dialects::init::DialectKind;
 use sqruff_lib_core::dialects::syntax::SyntaxKind;
 use sqruff_lib_core::helpers::{Config, ToMatchable};
-use sqruff_lib_core::parser::grammar::anyof::one_of;
+use sqruff_lib_core::parser::grammar::anyof::{one_of, AnyNumberOf};
 use sqruff_lib_core::parser::grammar::base::Ref;
 use sqruff_lib_core::parser::grammar::delimited::Delimited;
 use sqruff_lib_core::parser::grammar::sequence::{Bracketed, Sequence};
 use sqruff_lib_core::parser::lexer::Matcher;
 use sqruff_lib_core::parser::matchable::MatchableTrait;
+use sqruff_lib_core::parser::node_matcher::NodeMatcher;
 use sqruff_lib_core::parser::parsers::StringParser;
 use sqruff_lib_core::parser::segments::meta::MetaSegment;
 use sqruff_lib_core::vec_of_erased;
@@ -26,6 +27,7 @@ pub fn raw_dialect() -> Dialect {
 
     duckdb_dialect.add_keyword_to_set("reserved_keywords", "Summarize");
     duckdb_dialect.add_keyword_to_set("reserved_keywords", "Macro");
+    duckdb_dialect.add_keyword_to_set("unreserved_keywords", "Positional");
 
     duckdb_dialect.add([
         (
@@ -124,6 +126,29 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "JoinLikeClauseGrammar".into(),
+            Ref::new("PositionalJoinClauseSegment")
+                .to_matchable()
+                .into(),
+        ),
+        (
+            // https://duckdb.org/docs/sql/query_syntax/from.html#positional-joins
+            "PositionalJoinClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::PositionalJoinClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("POSITIONAL"),
+                    Ref::new("JoinKeywordsGrammar"),
+                    MetaSegment::indent(),
+                    Ref::new("FromExpressionElementSegment"),
+                    MetaSegment::dedent()
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
     ]);
 
     duckdb_dialect.insert_lexer_matchers(
@@ -241,6 +266,34 @@ pub fn raw_dialect() -> Dialect {
         .to_matchable(),
     );
 
+    duckdb_dialect.replace_grammar(
+        "ArrayAccessorSegment",
+        Bracketed::new(vec_of_erased![one_of(vec_of_erased![
+            // Single element access: [n]
+            Ref::new("QualifiedNumericLiteralSegment"),
+            Ref::new("NumericLiteralSegment"),
+            Ref::new("ExpressionSegment"),
+            // Slice access with an optional step: [n:m], [n:m:s], [:m], [n:], [::s]
+            Sequence::new(vec_of_erased![
+                slice_bound(),
+                Ref::new("SliceSegment"),
+                slice_bound(),
+                Sequence::new(vec_of_erased![Ref::new("SliceSegment"), slice_bound()])
+                    .config(|this| this.optional()),
+            ]),
+            // An omitted stop lexes as a cast operator: [n::s], [::s]
+            Sequence::new(vec_of_erased![
+                slice_bound(),
+                Ref::new("CastOperatorSegment"),
+                slice_bound(),
+            ]),
+        ])])
+        .config(|this| {
+            this.bracket_type("square");
+        })
+        .to_matchable(),
+    );
+
     duckdb_dialect.replace_grammar(
         "StatementSegment",
         postgres::statement_segment().copy(
@@ -260,3 +313,12 @@ pub fn raw_dialect() -> Dialect {
 
     duckdb_dialect
 }
+
+fn slice_bound() -> AnyNumberOf {
+    one_of(vec_of_erased![
+        Ref::new("QualifiedNumericLiteralSegment"),
+        Ref::new("NumericLiteralSegment"),
+        Ref::new("ExpressionSegment"),
+    ])
+    .config(|this| this.optional())
+}
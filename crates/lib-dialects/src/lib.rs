@@ -23,6 +23,8 @@ pub mod databricks_keywords;
 pub mod duckdb;
 #[cfg(feature = "hive")]
 pub mod hive;
+#[cfg(feature = "mysql")]
+pub mod mysql;
 #[cfg(feature = "postgres")]
 pub mod postgres;
 #[cfg(feature = "postgres")]
@@ -47,6 +49,29 @@ mod sqlite_keywords;
 pub mod trino;
 #[cfg(feature = "trino")]
 mod trino_keywords;
+#[cfg(feature = "tsql")]
+pub mod tsql;
+
+/// Whether the dialect was compiled in, i.e. whether [`kind_to_dialect`] would
+/// build it, without building it.
+pub fn is_dialect_enabled(kind: &DialectKind) -> bool {
+    match kind {
+        DialectKind::Ansi => true,
+        DialectKind::Athena => cfg!(feature = "athena"),
+        DialectKind::Bigquery => cfg!(feature = "bigquery"),
+        DialectKind::Clickhouse => cfg!(feature = "clickhouse"),
+        DialectKind::Databricks => cfg!(feature = "databricks"),
+        DialectKind::Duckdb => cfg!(feature = "duckdb"),
+        DialectKind::Mysql => cfg!(feature = "mysql"),
+        DialectKind::Postgres => cfg!(feature = "postgres"),
+        DialectKind::Redshift => cfg!(feature = "redshift"),
+        DialectKind::Snowflake => cfg!(feature = "snowflake"),
+        DialectKind::Sparksql => cfg!(feature = "sparksql"),
+        DialectKind::Sqlite => cfg!(feature = "sqlite"),
+        DialectKind::Trino => cfg!(feature = "trino"),
+        DialectKind::Tsql => cfg!(feature = "tsql"),
+    }
+}
 
 pub fn kind_to_dialect(kind: &DialectKind) -> Option<Dialect> {
     #[allow(unreachable_patterns)]
@@ -62,6 +87,8 @@ pub fn kind_to_dialect(kind: &DialectKind) -> Option<Dialect> {
         DialectKind::Databricks => databricks::dialect(),
         #[cfg(feature = "duckdb")]
         DialectKind::Duckdb => duckdb::dialect(),
+        #[cfg(feature = "mysql")]
+        DialectKind::Mysql => mysql::dialect(),
         #[cfg(feature = "postgres")]
         DialectKind::Postgres => postgres::dialect(),
         #[cfg(feature = "redshift")]
@@ -74,6 +101,40 @@ pub fn kind_to_dialect(kind: &DialectKind) -> Option<Dialect> {
         DialectKind::Sqlite => sqlite::dialect(),
         #[cfg(feature = "trino")]
         DialectKind::Trino => trino::dialect(),
+        #[cfg(feature = "tsql")]
+        DialectKind::Tsql => tsql::dialect(),
         _ => return None,
     })
 }
+
+/// Builds the dialect like [`kind_to_dialect`], with `batch_separator` ending batches in the
+/// dialects which split files into batches.
+#[cfg_attr(not(feature = "tsql"), allow(unused_variables))]
+pub fn kind_to_dialect_with_batch_separator(
+    kind: &DialectKind,
+    batch_separator: &str,
+) -> Option<Dialect> {
+    match kind {
+        #[cfg(feature = "tsql")]
+        DialectKind::Tsql => Some(tsql::dialect_with_batch_separator(batch_separator)),
+        _ => kind_to_dialect(kind),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn enabled_dialects_can_be_built() {
+        for kind in DialectKind::iter() {
+            assert_eq!(
+                is_dialect_enabled(&kind),
+                kind_to_dialect(&kind).is_some(),
+                "{kind:?}"
+            );
+        }
+    }
+}
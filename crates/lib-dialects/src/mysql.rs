@@ -0,0 +1,248 @@
+use sqruff_lib_core::dialects::base::Dialect;
+use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::syntax::SyntaxKind;
+use sqruff_lib_core::helpers::{Config, ToMatchable};
+use sqruff_lib_core::parser::grammar::anyof::{one_of, AnyNumberOf};
+use sqruff_lib_core::parser::grammar::base::Ref;
+use sqruff_lib_core::parser::grammar::delimited::Delimited;
+use sqruff_lib_core::parser::grammar::sequence::{Bracketed, Sequence};
+use sqruff_lib_core::parser::matchable::MatchableTrait;
+use sqruff_lib_core::parser::node_matcher::NodeMatcher;
+use sqruff_lib_core::parser::parsers::{StringParser, TypedParser};
+use sqruff_lib_core::vec_of_erased;
+
+use crate::ansi;
+
+pub fn dialect() -> Dialect {
+    raw_dialect().config(|dialect| dialect.expand())
+}
+
+pub fn raw_dialect() -> Dialect {
+    let mut mysql = ansi::raw_dialect();
+    mysql.name = DialectKind::Mysql;
+
+    mysql
+        .sets_mut("unreserved_keywords")
+        .extend(["CHARSET", "DUPLICATE", "ENGINE", "ROW_FORMAT"]);
+    // Index hints follow the table alias, so these can't be aliases.
+    mysql.sets_mut("reserved_keywords").extend(["FORCE", "USE"]);
+
+    mysql.add([
+        (
+            // Identifiers are quoted with backticks, double quotes delimit
+            // strings unless ANSI_QUOTES is enabled.
+            "QuotedIdentifierSegment".into(),
+            TypedParser::new(SyntaxKind::BackQuote, SyntaxKind::QuotedIdentifier)
+                .to_matchable()
+                .into(),
+        ),
+        (
+            "QuotedLiteralSegment".into(),
+            one_of(vec_of_erased![
+                TypedParser::new(SyntaxKind::SingleQuote, SyntaxKind::QuotedLiteral),
+                TypedParser::new(SyntaxKind::DoubleQuote, SyntaxKind::QuotedLiteral)
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "PostTableExpressionGrammar".into(),
+            AnyNumberOf::new(vec_of_erased![Ref::new("IndexHintClauseSegment")])
+                .config(|this| this.min_times = 1)
+                .to_matchable()
+                .into(),
+        ),
+        (
+            // https://dev.mysql.com/doc/refman/8.0/en/index-hints.html
+            "IndexHintClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::IndexHintClause,
+                Sequence::new(vec_of_erased![
+                    one_of(vec_of_erased![
+                        Ref::keyword("USE"),
+                        Ref::keyword("IGNORE"),
+                        Ref::keyword("FORCE")
+                    ]),
+                    one_of(vec_of_erased![Ref::keyword("INDEX"), Ref::keyword("KEY")]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("FOR"),
+                        one_of(vec_of_erased![
+                            Ref::keyword("JOIN"),
+                            Sequence::new(vec_of_erased![
+                                Ref::keyword("ORDER"),
+                                Ref::keyword("BY")
+                            ]),
+                            Sequence::new(vec_of_erased![
+                                Ref::keyword("GROUP"),
+                                Ref::keyword("BY")
+                            ])
+                        ])
+                    ])
+                    .config(|this| this.optional()),
+                    Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
+                        "ObjectReferenceSegment"
+                    )])
+                    .config(|this| this.optional())])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "InsertStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::InsertStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("INSERT"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("LOW_PRIORITY"),
+                        Ref::keyword("DELAYED"),
+                        Ref::keyword("HIGH_PRIORITY")
+                    ])
+                    .config(|this| this.optional()),
+                    Ref::keyword("IGNORE").optional(),
+                    Ref::keyword("INTO").optional(),
+                    Ref::new("TableReferenceSegment"),
+                    Ref::new("BracketedColumnReferenceListGrammar").optional(),
+                    one_of(vec_of_erased![
+                        Ref::new("SelectableGrammar"),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("SET"),
+                            Delimited::new(vec_of_erased![Ref::new("SetClauseSegment")])
+                        ])
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("ON"),
+                        Ref::keyword("DUPLICATE"),
+                        Ref::keyword("KEY"),
+                        Ref::keyword("UPDATE"),
+                        Delimited::new(vec_of_erased![Ref::new("SetClauseSegment")])
+                    ])
+                    .config(|this| this.optional())
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            // REPLACE deletes the rows which clash on a unique key before
+            // inserting, so unlike INSERT it has no ON DUPLICATE KEY UPDATE.
+            // https://dev.mysql.com/doc/refman/8.0/en/replace.html
+            "ReplaceStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ReplaceStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("REPLACE"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("LOW_PRIORITY"),
+                        Ref::keyword("DELAYED")
+                    ])
+                    .config(|this| this.optional()),
+                    Ref::keyword("INTO").optional(),
+                    Ref::new("TableReferenceSegment"),
+                    Ref::new("BracketedColumnReferenceListGrammar").optional(),
+                    one_of(vec_of_erased![
+                        Ref::new("SelectableGrammar"),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("SET"),
+                            Delimited::new(vec_of_erased![Ref::new("SetClauseSegment")])
+                        ])
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+    ]);
+
+    mysql.replace_grammar(
+        "StatementSegment",
+        ansi::statement_segment().copy(
+            Some(vec_of_erased![Ref::new("ReplaceStatementSegment")]),
+            None,
+            None,
+            None,
+            Vec::new(),
+            false,
+        ),
+    );
+
+    // https://dev.mysql.com/doc/refman/8.0/en/create-table.html
+    mysql.replace_grammar(
+        "TableEndClauseSegment",
+        AnyNumberOf::new(vec_of_erased![Sequence::new(vec_of_erased![
+            Ref::new("CommaSegment").optional(),
+            one_of(vec_of_erased![
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("DEFAULT").optional(),
+                    one_of(vec_of_erased![
+                        Ref::keyword("CHARSET"),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("CHARACTER"),
+                            Ref::keyword("SET")
+                        ])
+                    ]),
+                    Ref::new("EqualsSegment").optional(),
+                    Ref::new("NakedIdentifierSegment")
+                ]),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("DEFAULT").optional(),
+                    Ref::keyword("COLLATE"),
+                    Ref::new("EqualsSegment").optional(),
+                    Ref::new("CollationReferenceSegment")
+                ]),
+                Sequence::new(vec_of_erased![
+                    one_of(vec_of_erased![
+                        Ref::keyword("ENGINE"),
+                        Ref::keyword("ROW_FORMAT")
+                    ]),
+                    Ref::new("EqualsSegment").optional(),
+                    Ref::new("NakedIdentifierSegment")
+                ]),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("AUTO_INCREMENT"),
+                    Ref::new("EqualsSegment").optional(),
+                    Ref::new("NumericLiteralSegment")
+                ]),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("COMMENT"),
+                    Ref::new("EqualsSegment").optional(),
+                    Ref::new("QuotedLiteralSegment")
+                ])
+            ])
+        ])])
+        .config(|this| this.min_times = 1)
+        .to_matchable(),
+    );
+
+    // In `ON DUPLICATE KEY UPDATE`, `VALUES(col)` is the value the row would
+    // have been inserted with, a function rather than a values clause.
+    // https://dev.mysql.com/doc/refman/8.0/en/miscellaneous-functions.html#function_values
+    mysql.replace_grammar(
+        "FunctionSegment",
+        mysql
+            .grammar("FunctionSegment")
+            .match_grammar()
+            .unwrap()
+            .copy(
+                Some(vec_of_erased![Sequence::new(vec_of_erased![
+                    NodeMatcher::new(
+                        SyntaxKind::FunctionName,
+                        StringParser::new("VALUES", SyntaxKind::FunctionNameIdentifier)
+                            .to_matchable(),
+                    ),
+                    Bracketed::new(vec_of_erased![Ref::new("ColumnReferenceSegment")])
+                ])]),
+                Some(0),
+                None,
+                None,
+                Vec::new(),
+                false,
+            ),
+    );
+
+    mysql
+}
@@ -6,7 +6,7 @@ use sqruff_lib_core::helpers::{Config, ToMatchable};
 use sqruff_lib_core::parser::grammar::anyof::{
     any_set_of, one_of, optionally_bracketed, AnyNumberOf,
 };
-use sqruff_lib_core::parser::grammar::base::{Anything, Ref};
+use sqruff_lib_core::parser::grammar::base::{Anything, Nothing, Ref};
 use sqruff_lib_core::parser::grammar::delimited::Delimited;
 use sqruff_lib_core::parser::grammar::sequence::{Bracketed, Sequence};
 use sqruff_lib_core::parser::lexer::Matcher;
@@ -96,6 +96,13 @@ pub fn raw_dialect() -> Dialect {
             SyntaxKind::SingleQuote,
         ),
         Matcher::regex("double_quote", r#"(?s)".+?""#, SyntaxKind::DoubleQuote),
+        // Tags can't start with a digit, so `$1` is left to `dollar_numeric_literal`.
+        Matcher::legacy(
+            "dollar_quote",
+            |s| s.starts_with("$"),
+            r"\$((?:[a-zA-Z_]\w*)?)\$[\s\S]*?\$\1\$",
+            SyntaxKind::DollarQuote,
+        ),
         Matcher::regex("word", r"[a-zA-Z_][0-9a-zA-Z_$]*", SyntaxKind::Word),
     ]);
 
@@ -159,6 +166,23 @@ pub fn raw_dialect() -> Dialect {
                 .to_matchable()
                 .into(),
         ),
+        (
+            "ForSystemTimeClauseSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "SchemaElementGrammar".into(),
+            one_of(vec_of_erased![
+                Ref::new("CreateTableStatementSegment"),
+                Ref::new("CreateViewStatementSegment"),
+                Ref::new("CreateIndexStatementSegment"),
+                Ref::new("CreateSequenceStatementSegment"),
+                Ref::new("CreateTriggerStatementSegment"),
+                Ref::new("AccessStatementSegment")
+            ])
+            .to_matchable()
+            .into(),
+        ),
         (
             "SimpleGeometryGrammar".into(),
             AnyNumberOf::new(vec_of_erased![Ref::new("NumericLiteralSegment")])
@@ -335,9 +359,15 @@ pub fn raw_dialect() -> Dialect {
             ),
             (
                 "StringBinaryOperatorGrammar".into(),
-                one_of(vec_of_erased![
-                    Ref::new("ConcatSegment"),
+                one_of(vec_of_erased![Ref::new("ConcatSegment")])
+                    .to_matchable()
+                    .into(),
+            ),
+            (
+                "CollateGrammar".into(),
+                Sequence::new(vec_of_erased![
                     Ref::keyword("COLLATE"),
+                    Ref::new("CollationReferenceSegment"),
                 ])
                 .to_matchable()
                 .into(),
@@ -548,6 +578,10 @@ pub fn raw_dialect() -> Dialect {
                 .to_matchable()
                 .into(),
             ),
+            (
+                "PreTableFunctionKeywordsGrammar".into(),
+                Ref::keyword("LATERAL").to_matchable().into(),
+            ),
             (
                 "SelectClauseTerminatorGrammar".into(),
                 one_of(vec_of_erased![
@@ -2085,27 +2119,23 @@ pub fn raw_dialect() -> Dialect {
         .into(),
     )]);
 
-    postgres.replace_grammar(
-        "ExplainStatementSegment",
-        Sequence::new(vec_of_erased![
-            Ref::keyword("EXPLAIN"),
-            one_of(vec_of_erased![
-                Sequence::new(vec_of_erased![
-                    one_of(vec_of_erased![
-                        Ref::keyword("ANALYZE").optional(),
-                        Ref::keyword("ANALYSE").optional(),
-                    ]),
-                    Ref::keyword("VERBOSE").optional(),
+    postgres.add([(
+        "ExplainOptionsGrammar".into(),
+        one_of(vec_of_erased![
+            Sequence::new(vec_of_erased![
+                one_of(vec_of_erased![
+                    Ref::keyword("ANALYZE").optional(),
+                    Ref::keyword("ANALYSE").optional(),
                 ]),
-                Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
-                    "ExplainOptionSegment"
-                )])]),
-            ])
-            .config(|this| this.optional()),
-            ansi::explainable_stmt(),
+                Ref::keyword("VERBOSE").optional(),
+            ]),
+            Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
+                "ExplainOptionSegment"
+            )])]),
         ])
-        .to_matchable(),
-    );
+        .to_matchable()
+        .into(),
+    )]);
 
     postgres.add([(
         "ExplainOptionSegment".into(),
@@ -2156,6 +2186,7 @@ pub fn raw_dialect() -> Dialect {
                 ]),
                 Ref::new("SchemaReferenceSegment"),
             ]),
+            AnyNumberOf::new(vec_of_erased![Ref::new("SchemaElementGrammar")]),
         ])
         .to_matchable(),
     );
@@ -2763,7 +2794,7 @@ pub fn raw_dialect() -> Dialect {
                             ]),
                             Sequence::new(vec_of_erased![
                                 Ref::keyword("ENABLE"),
-                                Ref::keyword("RULE"),
+                                Ref::keyword("ALWAYS"),
                             ]),
                         ]),
                         Ref::keyword("RULE"),
@@ -3325,24 +3356,6 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
-        (
-            "WithCheckOptionSegment".into(),
-            NodeMatcher::new(
-                SyntaxKind::WithCheckOption,
-                Sequence::new(vec_of_erased![
-                    Ref::keyword("WITH"),
-                    one_of(vec_of_erased![
-                        Ref::keyword("CASCADED"),
-                        Ref::keyword("LOCAL")
-                    ]),
-                    Ref::keyword("CHECK"),
-                    Ref::keyword("OPTION"),
-                ])
-                .to_matchable(),
-            )
-            .to_matchable()
-            .into(),
-        ),
         (
             "AlterPolicyStatementSegment".into(),
             NodeMatcher::new(
@@ -5708,6 +5721,103 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "DeclareStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::DeclareStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("DECLARE"),
+                    Ref::new("ObjectReferenceSegment"),
+                    Ref::keyword("BINARY").optional(),
+                    one_of(vec_of_erased![
+                        Ref::keyword("ASENSITIVE"),
+                        Ref::keyword("INSENSITIVE")
+                    ])
+                    .config(|this| this.optional()),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("NO").optional(),
+                        Ref::keyword("SCROLL")
+                    ])
+                    .config(|this| this.optional()),
+                    Ref::keyword("CURSOR"),
+                    Sequence::new(vec_of_erased![
+                        one_of(vec_of_erased![
+                            Ref::keyword("WITH"),
+                            Ref::keyword("WITHOUT")
+                        ]),
+                        Ref::keyword("HOLD")
+                    ])
+                    .config(|this| this.optional()),
+                    Ref::keyword("FOR"),
+                    Ref::new("SelectableGrammar")
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "FetchStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::FetchStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("FETCH"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("NEXT"),
+                        Ref::keyword("PRIOR"),
+                        Ref::keyword("FIRST"),
+                        Ref::keyword("LAST"),
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![
+                                Ref::keyword("ABSOLUTE"),
+                                Ref::keyword("RELATIVE")
+                            ]),
+                            Ref::new("SignedSegmentGrammar").optional(),
+                            Ref::new("NumericLiteralSegment")
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::new("SignedSegmentGrammar").optional(),
+                            Ref::new("NumericLiteralSegment")
+                        ]),
+                        Ref::keyword("ALL"),
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![
+                                Ref::keyword("FORWARD"),
+                                Ref::keyword("BACKWARD")
+                            ]),
+                            one_of(vec_of_erased![
+                                Ref::keyword("ALL"),
+                                Ref::new("NumericLiteralSegment")
+                            ])
+                            .config(|this| this.optional())
+                        ])
+                    ])
+                    .config(|this| this.optional()),
+                    one_of(vec_of_erased![Ref::keyword("FROM"), Ref::keyword("IN")])
+                        .config(|this| this.optional()),
+                    Ref::new("ObjectReferenceSegment")
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "CloseStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::CloseStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("CLOSE"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("ALL"),
+                        Ref::new("ObjectReferenceSegment")
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "ListenStatementSegment".into(),
             NodeMatcher::new(
@@ -6735,6 +6845,9 @@ pub fn statement_segment() -> Matchable {
             Ref::new("AlterFunctionStatementSegment"),
             Ref::new("CreateViewStatementSegment"),
             Ref::new("AlterViewStatementSegment"),
+            Ref::new("DeclareStatementSegment"),
+            Ref::new("FetchStatementSegment"),
+            Ref::new("CloseStatementSegment"),
             Ref::new("ListenStatementSegment"),
             Ref::new("NotifyStatementSegment"),
             Ref::new("UnlistenStatementSegment"),
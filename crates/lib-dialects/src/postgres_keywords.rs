@@ -70,7 +70,7 @@ const POSTGRES_DOCS_KEYWORDS: &[(&str, &str)] = &[
     ("ARRAY_MAX_CARDINALITY", "not-keyword"),
     ("AS", "reserved"),
     ("ASC", "reserved"),
-    ("ASENSITIVE", "not-keyword"),
+    ("ASENSITIVE", "non-reserved"),
     ("ASIN", "not-keyword"),
     ("ASSERTION", "non-reserved"),
     ("ASSIGNMENT", "non-reserved"),
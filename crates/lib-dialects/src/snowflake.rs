@@ -970,6 +970,10 @@ pub fn dialect() -> Dialect {
             "TrimParametersGrammar".into(),
             Nothing::new().to_matchable().into(),
         ),
+        (
+            "WithCheckOptionSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
         (
             "GroupByClauseTerminatorGrammar".into(),
             one_of(vec_of_erased![
@@ -1300,6 +1304,7 @@ pub fn dialect() -> Dialect {
                 Ref::new("CreateProcedureStatementSegment"),
                 Ref::new("ScriptingBlockStatementSegment"),
                 Ref::new("ScriptingLetStatementSegment"),
+                Ref::new("ScriptingDeclareStatementSegment"),
                 Ref::new("ReturnStatementSegment"),
                 Ref::new("ShowStatementSegment"),
                 Ref::new("AlterUserStatementSegment"),
@@ -3275,6 +3280,58 @@ pub fn dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "ScriptingDeclareStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::DeclareStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("DECLARE"),
+                    Ref::new("LocalVariableNameSegment"),
+                    one_of(vec_of_erased![
+                        // Cursor declaration
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("CURSOR"),
+                            Ref::keyword("FOR"),
+                            Ref::new("SelectableGrammar"),
+                        ]),
+                        // Resultset declaration
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("RESULTSET"),
+                            Sequence::new(vec_of_erased![
+                                one_of(vec_of_erased![
+                                    Ref::keyword("DEFAULT"),
+                                    Ref::new("WalrusOperatorSegment"),
+                                ]),
+                                Bracketed::new(vec_of_erased![Ref::new("SelectableGrammar"),]),
+                            ])
+                            .config(|this| this.optional()),
+                        ]),
+                        // Variable declaration
+                        Sequence::new(vec_of_erased![
+                            Ref::new("DatatypeSegment"),
+                            Sequence::new(vec_of_erased![
+                                one_of(vec_of_erased![
+                                    Ref::keyword("DEFAULT"),
+                                    Ref::new("WalrusOperatorSegment"),
+                                ]),
+                                Ref::new("ExpressionSegment"),
+                            ])
+                            .config(|this| this.optional()),
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![
+                                Ref::keyword("DEFAULT"),
+                                Ref::new("WalrusOperatorSegment"),
+                            ]),
+                            Ref::new("ExpressionSegment"),
+                        ]),
+                    ]),
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "CreateFunctionStatementSegment".into(),
             NodeMatcher::new(
@@ -6581,23 +6638,19 @@ pub fn dialect() -> Dialect {
         .to_matchable(),
     );
 
-    snowflake_dialect.replace_grammar(
-        "ExplainStatementSegment",
+    snowflake_dialect.add([(
+        "ExplainOptionsGrammar".into(),
         Sequence::new(vec_of_erased![
-            Ref::keyword("EXPLAIN"),
-            Sequence::new(vec_of_erased![
-                Ref::keyword("USING"),
-                one_of(vec_of_erased![
-                    Ref::keyword("TABULAR"),
-                    Ref::keyword("JSON"),
-                    Ref::keyword("TEXT"),
-                ]),
-            ])
-            .config(|this| this.optional()),
-            ansi::explainable_stmt(),
+            Ref::keyword("USING"),
+            one_of(vec_of_erased![
+                Ref::keyword("TABULAR"),
+                Ref::keyword("JSON"),
+                Ref::keyword("TEXT"),
+            ]),
         ])
-        .to_matchable(),
-    );
+        .to_matchable()
+        .into(),
+    )]);
 
     snowflake_dialect.add([
         (
@@ -6775,6 +6828,13 @@ pub fn dialect() -> Dialect {
         ),
     ]);
 
+    snowflake_dialect.add([(
+        "MergeNotMatchedByTargetGrammar".into(),
+        Sequence::new(vec_of_erased![Ref::keyword("BY"), Ref::keyword("TARGET")])
+            .to_matchable()
+            .into(),
+    )]);
+
     snowflake_dialect.replace_grammar(
         "MergeUpdateClauseSegment",
         Sequence::new(vec_of_erased![
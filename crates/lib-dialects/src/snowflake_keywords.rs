@@ -197,6 +197,7 @@ DATA_RETENTION_TIME_IN_DAYS
 DATE
 DATE_FORMAT
 DAYS_TO_EXPIRY
+DECLARE
 DEFAULT
 DEFAULT_DDL_COLLATION
 DEFAULT_NAMESPACE
@@ -533,6 +534,7 @@ TABLES
 TABLESPACE
 TABULAR
 TAG
+TARGET
 TARGET_PATH
 TASK
 TASKS
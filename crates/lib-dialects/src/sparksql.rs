@@ -2506,19 +2506,24 @@ pub fn raw_dialect() -> Dialect {
         ),
     ]);
 
+    sparksql_dialect.add([(
+        "ExplainOptionsGrammar".into(),
+        one_of(vec_of_erased![
+            Ref::keyword("EXTENDED"),
+            Ref::keyword("CODEGEN"),
+            Ref::keyword("COST"),
+            Ref::keyword("FORMATTED")
+        ])
+        .to_matchable()
+        .into(),
+    )]);
+
+    // Any statement can be explained in Spark.
     sparksql_dialect.replace_grammar(
         "ExplainStatementSegment",
         Sequence::new(vec_of_erased![
             Ref::keyword("EXPLAIN"),
-            one_of(vec_of_erased![
-                Ref::keyword("EXTENDED"),
-                Ref::keyword("CODEGEN"),
-                Ref::keyword("COST"),
-                Ref::keyword("FORMATTED")
-            ])
-            .config(|config| {
-                config.optional();
-            }),
+            Ref::new("ExplainOptionsGrammar").optional(),
             Ref::new("StatementSegment")
         ])
         .to_matchable(),
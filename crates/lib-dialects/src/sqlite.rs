@@ -112,6 +112,35 @@ pub fn raw_dialect() -> Dialect {
             "FetchClauseSegment".into(),
             Nothing::new().to_matchable().into(),
         ),
+        (
+            "GroupingSetsClauseSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "WithCheckOptionSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "ForSystemTimeClauseSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "PeriodDefinitionSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "TemporaryGrammar".into(),
+            one_of(vec_of_erased![
+                Ref::keyword("TEMP"),
+                Ref::keyword("TEMPORARY")
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "OnCommitGrammar".into(),
+            Nothing::new().to_matchable().into(),
+        ),
         (
             "TrimParametersGrammar".into(),
             Nothing::new().to_matchable().into(),
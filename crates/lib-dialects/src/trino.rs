@@ -113,6 +113,16 @@ pub fn dialect() -> Dialect {
                     ]),
                     TypedParser::new(SyntaxKind::SingleQuote, SyntaxKind::DateConstructorLiteral)
                 ]),
+                // https://trino.io/docs/current/language/types.html
+                Sequence::new(vec_of_erased![
+                    one_of(vec_of_erased![
+                        Ref::keyword("DECIMAL"),
+                        Ref::keyword("DOUBLE"),
+                        Ref::keyword("REAL"),
+                        Ref::keyword("JSON")
+                    ]),
+                    Ref::new("QuotedLiteralSegment")
+                ]),
                 Ref::new("IntervalExpressionSegment")
             ])
             .to_matchable()
@@ -128,6 +138,22 @@ pub fn dialect() -> Dialect {
             "MLTableExpressionSegment".into(),
             Nothing::new().to_matchable().into(),
         ),
+        (
+            "WithCheckOptionSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "ForSystemTimeClauseSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "PeriodDefinitionSegment".into(),
+            Nothing::new().to_matchable().into(),
+        ),
+        (
+            "OnCommitGrammar".into(),
+            Nothing::new().to_matchable().into(),
+        ),
         (
             "FromClauseTerminatorGrammar".into(),
             one_of(vec_of_erased![
@@ -372,6 +398,41 @@ pub fn dialect() -> Dialect {
         .into(),
     )]);
 
+    // https://trino.io/docs/current/sql/explain.html
+    // https://trino.io/docs/current/sql/explain-analyze.html
+    trino_dialect.add([(
+        "ExplainOptionsGrammar".into(),
+        one_of(vec_of_erased![
+            Sequence::new(vec_of_erased![
+                Ref::keyword("ANALYZE"),
+                Ref::keyword("VERBOSE").optional()
+            ]),
+            Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![one_of(
+                vec_of_erased![
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("FORMAT"),
+                        one_of(vec_of_erased![
+                            Ref::keyword("TEXT"),
+                            Ref::keyword("GRAPHVIZ"),
+                            Ref::keyword("JSON")
+                        ])
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("TYPE"),
+                        one_of(vec_of_erased![
+                            Ref::keyword("LOGICAL"),
+                            Ref::keyword("DISTRIBUTED"),
+                            Ref::keyword("VALIDATE"),
+                            Ref::keyword("IO")
+                        ])
+                    ])
+                ]
+            )])])
+        ])
+        .to_matchable()
+        .into(),
+    )]);
+
     trino_dialect.replace_grammar(
         "GroupByClauseSegment",
         Sequence::new(vec_of_erased![
@@ -551,12 +612,36 @@ pub fn dialect() -> Dialect {
         .to_matchable(),
     );
 
+    // https://trino.io/docs/current/sql/select.html#unnest
+    trino_dialect.replace_grammar(
+        "TableExpressionSegment",
+        one_of(vec_of_erased![
+            Ref::new("ValuesClauseSegment"),
+            Ref::new("BareFunctionSegment"),
+            Sequence::new(vec_of_erased![
+                Ref::new("FunctionSegment"),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("WITH"),
+                    Ref::keyword("ORDINALITY")
+                ])
+                .config(|config| {
+                    config.optional();
+                })
+            ]),
+            Ref::new("TableReferenceSegment"),
+            Bracketed::new(vec_of_erased![Ref::new("SelectableGrammar")]),
+            Bracketed::new(vec_of_erased![Ref::new("MergeStatementSegment")])
+        ])
+        .to_matchable(),
+    );
+
     trino_dialect.replace_grammar(
         "StatementSegment",
         super::ansi::statement_segment().copy(
             Some(vec_of_erased![
                 Ref::new("AnalyzeStatementSegment"),
-                Ref::new("CommentOnStatementSegment")
+                Ref::new("CommentOnStatementSegment"),
+                Ref::new("ShowStatementSegment")
             ]),
             None,
             None,
@@ -772,12 +857,69 @@ pub fn dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            // https://trino.io/docs/current/sql/show-catalogs.html
+            "ShowStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ShowStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("SHOW"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("CATALOGS"),
+                        Ref::keyword("SESSION"),
+                        Ref::keyword("FUNCTIONS"),
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![
+                                Ref::keyword("SCHEMAS"),
+                                Ref::keyword("TABLES"),
+                                Ref::keyword("COLUMNS")
+                            ]),
+                            Sequence::new(vec_of_erased![
+                                one_of(vec_of_erased![Ref::keyword("FROM"), Ref::keyword("IN")]),
+                                Ref::new("ObjectReferenceSegment")
+                            ])
+                            .config(|config| {
+                                config.optional();
+                            })
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("CREATE"),
+                            one_of(vec_of_erased![
+                                Ref::keyword("SCHEMA"),
+                                Ref::keyword("TABLE"),
+                                Ref::keyword("VIEW")
+                            ]),
+                            Ref::new("ObjectReferenceSegment")
+                        ])
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("LIKE"),
+                        Ref::new("QuotedLiteralSegment"),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("ESCAPE"),
+                            Ref::new("QuotedLiteralSegment")
+                        ])
+                        .config(|config| {
+                            config.optional();
+                        })
+                    ])
+                    .config(|config| {
+                        config.optional();
+                    })
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "LambdaExpressionSegment".into(),
             Sequence::new(vec_of_erased![
                 one_of(vec_of_erased![
                     Ref::new("ParameterNameSegment"),
-                    Bracketed::new(vec_of_erased![Ref::new("ParameterNameSegment")]),
+                    Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
+                        "ParameterNameSegment"
+                    )])]),
                 ]),
                 Ref::new("LambdaArrowSegment"),
                 Ref::new("ExpressionSegment"),
@@ -0,0 +1,498 @@
+use sqruff_lib_core::dialects::base::Dialect;
+use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::syntax::SyntaxKind;
+use sqruff_lib_core::helpers::{Config, ToMatchable};
+use sqruff_lib_core::parser::grammar::anyof::{one_of, AnyNumberOf};
+use sqruff_lib_core::parser::grammar::base::Ref;
+use sqruff_lib_core::parser::grammar::delimited::Delimited;
+use sqruff_lib_core::parser::grammar::sequence::{Bracketed, Sequence};
+use sqruff_lib_core::parser::lexer::{Matcher, Pattern};
+use sqruff_lib_core::parser::matchable::MatchableTrait;
+use sqruff_lib_core::parser::node_matcher::NodeMatcher;
+use sqruff_lib_core::parser::parsers::TypedParser;
+use sqruff_lib_core::parser::segments::meta::MetaSegment;
+use sqruff_lib_core::vec_of_erased;
+
+use crate::ansi;
+
+pub fn dialect() -> Dialect {
+    raw_dialect().config(|dialect| dialect.expand())
+}
+
+/// The dialect, with batches ended by `separator` rather than `GO`.
+pub fn dialect_with_batch_separator(separator: &str) -> Dialect {
+    raw_dialect().config(|dialect| {
+        dialect.patch_lexer_matchers(vec![batch_separator(separator)]);
+        dialect.expand();
+    })
+}
+
+/// Lexes `separator` as a batch separator when it is alone on its line, save for a repeat
+/// count as in `GO 5` and a trailing comment.
+///
+/// Matchers can't look behind, so this one starts at the newline ending the previous line and
+/// splits it, and any indent, back off.
+fn batch_separator(separator: &str) -> Matcher {
+    let separator = separator.chars().fold(String::new(), |mut escaped, ch| {
+        if r"\.+*?()|[]{}^$".contains(ch) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+        escaped
+    });
+
+    Matcher::legacy(
+        "batch_separator",
+        |s| s.starts_with(['\r', '\n']),
+        &format!(
+            r"(\r\n|\n)[^\S\r\n]*(?i:{separator})(?=([^\S\r\n]+\d+)?[^\S\r\n]*(--[^\n]*)?(\r\n|\n|$))"
+        ),
+        SyntaxKind::BatchSeparator,
+    )
+    .subdivider(Pattern::legacy(
+        "newline",
+        |_| true,
+        r"\r\n|\n",
+        SyntaxKind::Newline,
+    ))
+    .post_subdivide(Pattern::legacy(
+        "whitespace",
+        |_| true,
+        r"[^\S\r\n]+",
+        SyntaxKind::Whitespace,
+    ))
+}
+
+pub fn raw_dialect() -> Dialect {
+    let mut tsql = ansi::raw_dialect();
+    tsql.name = DialectKind::Tsql;
+
+    // Square brackets quote identifiers, there are no array literals.
+    tsql.insert_lexer_matchers(
+        vec![Matcher::regex(
+            "square_quote",
+            r"\[([^\]]|\]\])*\]",
+            SyntaxKind::SquareQuote,
+        )],
+        "start_square_bracket",
+    );
+    tsql.insert_lexer_matchers(
+        vec![Matcher::regex(
+            "at_sign_literal",
+            r"@@?[a-zA-Z_][\w@$#]*",
+            SyntaxKind::AtSignLiteral,
+        )],
+        "equals",
+    );
+    tsql.insert_lexer_matchers(vec![batch_separator("GO")], "newline");
+
+    tsql.sets_mut("unreserved_keywords").extend([
+        "ABSENT",
+        "AUTO",
+        "BASE64",
+        "ELEMENTS",
+        "EXPLICIT",
+        "FORCESCAN",
+        "FORCESEEK",
+        "INCLUDE_NULL_VALUES",
+        "NOEXPAND",
+        "NOLOCK",
+        "PAGLOCK",
+        "READCOMMITTED",
+        "READPAST",
+        "READUNCOMMITTED",
+        "REPEATABLEREAD",
+        "ROOT",
+        "ROWLOCK",
+        "TABLOCK",
+        "TABLOCKX",
+        "UPDLOCK",
+        "WITHOUT_ARRAY_WRAPPER",
+        "XLOCK",
+        "XMLDATA",
+        "XMLSCHEMA",
+        "XSINIL",
+    ]);
+    // These end a statement or a block, so they can't be aliases.
+    tsql.sets_mut("reserved_keywords")
+        .extend(["END", "FOR", "TOP", "TRAN", "TRANSACTION"]);
+
+    tsql.add([
+        (
+            "QuotedIdentifierSegment".into(),
+            one_of(vec_of_erased![
+                TypedParser::new(SyntaxKind::SquareQuote, SyntaxKind::QuotedIdentifier),
+                TypedParser::new(SyntaxKind::DoubleQuote, SyntaxKind::QuotedIdentifier)
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "AtSignLiteralSegment".into(),
+            TypedParser::new(SyntaxKind::AtSignLiteral, SyntaxKind::AtSignLiteral)
+                .to_matchable()
+                .into(),
+        ),
+        (
+            "ParameterizedSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ParameterizedExpression,
+                Ref::new("AtSignLiteralSegment").to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "LiteralGrammar".into(),
+            tsql.grammar("LiteralGrammar")
+                .copy(
+                    Some(vec_of_erased![Ref::new("ParameterizedSegment")]),
+                    None,
+                    None,
+                    None,
+                    Vec::new(),
+                    false,
+                )
+                .into(),
+        ),
+        (
+            // https://learn.microsoft.com/en-us/sql/sql-server/utilities/sql-server-utilities-statements-go
+            "BatchSeparatorSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::BatchSeparator,
+                Sequence::new(vec_of_erased![
+                    TypedParser::new(SyntaxKind::BatchSeparator, SyntaxKind::Keyword),
+                    Ref::new("NumericLiteralSegment").optional()
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "DelimiterGrammar".into(),
+            one_of(vec_of_erased![
+                Ref::new("SemicolonSegment"),
+                Ref::new("BatchSeparatorSegment")
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "PostTableExpressionGrammar".into(),
+            Ref::new("TableHintClauseSegment").to_matchable().into(),
+        ),
+        (
+            // https://learn.microsoft.com/en-us/sql/t-sql/queries/hints-transact-sql-table
+            "TableHintClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::TableHintClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("WITH"),
+                    Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![one_of(
+                        vec_of_erased![
+                            Ref::keyword("FORCESCAN"),
+                            Ref::keyword("FORCESEEK"),
+                            Ref::keyword("HOLDLOCK"),
+                            Ref::keyword("NOEXPAND"),
+                            Ref::keyword("NOLOCK"),
+                            Ref::keyword("NOWAIT"),
+                            Ref::keyword("PAGLOCK"),
+                            Ref::keyword("READCOMMITTED"),
+                            Ref::keyword("READPAST"),
+                            Ref::keyword("READUNCOMMITTED"),
+                            Ref::keyword("REPEATABLEREAD"),
+                            Ref::keyword("ROWLOCK"),
+                            Ref::keyword("SERIALIZABLE"),
+                            Ref::keyword("TABLOCK"),
+                            Ref::keyword("TABLOCKX"),
+                            Ref::keyword("UPDLOCK"),
+                            Ref::keyword("XLOCK"),
+                            Sequence::new(vec_of_erased![
+                                Ref::keyword("INDEX"),
+                                one_of(vec_of_erased![
+                                    Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![
+                                        Ref::new("ObjectReferenceSegment")
+                                    ])]),
+                                    Sequence::new(vec_of_erased![
+                                        Ref::new("EqualsSegment"),
+                                        Ref::new("ObjectReferenceSegment")
+                                    ])
+                                ])
+                            ])
+                        ]
+                    )])])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            // https://learn.microsoft.com/en-us/sql/t-sql/language-elements/declare-local-variable-transact-sql
+            "DeclareStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::DeclareStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("DECLARE"),
+                    Delimited::new(vec_of_erased![Sequence::new(vec_of_erased![
+                        Ref::new("ParameterizedSegment"),
+                        Ref::keyword("AS").optional(),
+                        Ref::new("DatatypeSegment"),
+                        Sequence::new(vec_of_erased![
+                            Ref::new("EqualsSegment"),
+                            Ref::new("ExpressionSegment")
+                        ])
+                        .config(|this| this.optional())
+                    ])])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "SetStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::SetStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("SET"),
+                    Ref::new("ParameterizedSegment"),
+                    Ref::new("EqualsSegment"),
+                    Ref::new("ExpressionSegment")
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            // https://learn.microsoft.com/en-us/sql/t-sql/language-elements/begin-end-transact-sql
+            "BeginEndBlockSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::BeginEndBlock,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("BEGIN"),
+                    MetaSegment::indent(),
+                    AnyNumberOf::new(vec_of_erased![Sequence::new(vec_of_erased![
+                        Ref::new("StatementSegment"),
+                        Ref::new("DelimiterGrammar").optional()
+                    ])])
+                    .config(|this| {
+                        this.min_times = 1;
+                        this.terminators = vec_of_erased![Ref::keyword("END")];
+                    }),
+                    MetaSegment::dedent(),
+                    Ref::keyword("END")
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+    ]);
+
+    // https://learn.microsoft.com/en-us/sql/relational-databases/json/format-query-results-as-json-with-for-json-sql-server
+    // https://learn.microsoft.com/en-us/sql/relational-databases/xml/for-xml-sql-server
+    tsql.add([(
+        "ForClauseSegment".into(),
+        NodeMatcher::new(
+            SyntaxKind::ForClause,
+            Sequence::new(vec_of_erased![
+                Ref::keyword("FOR"),
+                one_of(vec_of_erased![
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("JSON"),
+                        one_of(vec_of_erased![Ref::keyword("AUTO"), Ref::keyword("PATH")]),
+                        AnyNumberOf::new(vec_of_erased![Sequence::new(vec_of_erased![
+                            Ref::new("CommaSegment"),
+                            one_of(vec_of_erased![
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("ROOT"),
+                                    Bracketed::new(vec_of_erased![Ref::new(
+                                        "QuotedLiteralSegment"
+                                    )])
+                                    .config(|this| this.optional())
+                                ]),
+                                Ref::keyword("INCLUDE_NULL_VALUES"),
+                                Ref::keyword("WITHOUT_ARRAY_WRAPPER")
+                            ])
+                        ])])
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("XML"),
+                        one_of(vec_of_erased![
+                            Sequence::new(vec_of_erased![
+                                one_of(vec_of_erased![Ref::keyword("PATH"), Ref::keyword("RAW")]),
+                                Bracketed::new(vec_of_erased![Ref::new("QuotedLiteralSegment")])
+                                    .config(|this| this.optional())
+                            ]),
+                            Ref::keyword("AUTO"),
+                            Ref::keyword("EXPLICIT")
+                        ]),
+                        AnyNumberOf::new(vec_of_erased![Sequence::new(vec_of_erased![
+                            Ref::new("CommaSegment"),
+                            one_of(vec_of_erased![
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("ROOT"),
+                                    Bracketed::new(vec_of_erased![Ref::new(
+                                        "QuotedLiteralSegment"
+                                    )])
+                                    .config(|this| this.optional())
+                                ]),
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("ELEMENTS"),
+                                    one_of(vec_of_erased![
+                                        Ref::keyword("XSINIL"),
+                                        Ref::keyword("ABSENT")
+                                    ])
+                                    .config(|this| this.optional())
+                                ]),
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("BINARY"),
+                                    Ref::keyword("BASE64")
+                                ]),
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("XMLSCHEMA"),
+                                    Bracketed::new(vec_of_erased![Ref::new(
+                                        "QuotedLiteralSegment"
+                                    )])
+                                    .config(|this| this.optional())
+                                ]),
+                                Ref::keyword("TYPE"),
+                                Ref::keyword("XMLDATA")
+                            ])
+                        ])])
+                    ])
+                ])
+            ])
+            .to_matchable(),
+        )
+        .to_matchable()
+        .into(),
+    )]);
+
+    // FOR JSON and FOR XML end whichever clause comes last in the SELECT.
+    for name in [
+        "SelectClauseTerminatorGrammar",
+        "FromClauseTerminatorGrammar",
+        "WhereClauseTerminatorGrammar",
+        "GroupByClauseTerminatorGrammar",
+        "HavingClauseTerminatorGrammar",
+        "OrderByClauseTerminators",
+    ] {
+        let terminators = tsql.grammar(name).copy(
+            Some(vec_of_erased![Sequence::new(vec_of_erased![
+                Ref::keyword("FOR"),
+                one_of(vec_of_erased![Ref::keyword("JSON"), Ref::keyword("XML")])
+            ])]),
+            None,
+            None,
+            None,
+            Vec::new(),
+            false,
+        );
+        tsql.add([(name.into(), terminators.into())]);
+    }
+
+    tsql.replace_grammar(
+        "SelectStatementSegment",
+        tsql.grammar("SelectStatementSegment")
+            .match_grammar()
+            .unwrap()
+            .copy(
+                Some(vec_of_erased![Ref::new("ForClauseSegment").optional()]),
+                None,
+                None,
+                None,
+                Vec::new(),
+                false,
+            ),
+    );
+    tsql.replace_grammar(
+        "UnorderedSelectStatementSegment",
+        tsql.grammar("UnorderedSelectStatementSegment")
+            .match_grammar()
+            .unwrap()
+            .copy(
+                Some(vec_of_erased![Ref::new("ForClauseSegment").optional()]),
+                None,
+                None,
+                None,
+                Vec::new(),
+                false,
+            ),
+    );
+
+    tsql.replace_grammar(
+        "StatementSegment",
+        ansi::statement_segment().copy(
+            Some(vec_of_erased![
+                Ref::new("BeginEndBlockSegment"),
+                Ref::new("DeclareStatementSegment"),
+                Ref::new("SetStatementSegment")
+            ]),
+            None,
+            None,
+            None,
+            Vec::new(),
+            false,
+        ),
+    );
+
+    // https://learn.microsoft.com/en-us/sql/t-sql/queries/top-transact-sql
+    tsql.replace_grammar(
+        "SelectClauseModifierSegment",
+        Sequence::new(vec_of_erased![
+            one_of(vec_of_erased![
+                Ref::keyword("DISTINCT"),
+                Ref::keyword("ALL")
+            ])
+            .config(|this| this.optional()),
+            Sequence::new(vec_of_erased![
+                Ref::keyword("TOP"),
+                one_of(vec_of_erased![
+                    Ref::new("NumericLiteralSegment"),
+                    Bracketed::new(vec_of_erased![Ref::new("ExpressionSegment")])
+                ]),
+                Ref::keyword("PERCENT").optional(),
+                Sequence::new(vec_of_erased![Ref::keyword("WITH"), Ref::keyword("TIES")])
+                    .config(|this| this.optional())
+            ])
+            .config(|this| this.optional())
+        ])
+        .to_matchable(),
+    );
+
+    // A bare BEGIN opens a block, transactions are always spelled out.
+    // https://learn.microsoft.com/en-us/sql/t-sql/language-elements/transactions-transact-sql
+    tsql.replace_grammar(
+        "TransactionStatementSegment",
+        one_of(vec_of_erased![
+            Sequence::new(vec_of_erased![
+                one_of(vec_of_erased![Ref::keyword("BEGIN"), Ref::keyword("SAVE")]),
+                one_of(vec_of_erased![
+                    Ref::keyword("TRAN"),
+                    Ref::keyword("TRANSACTION")
+                ]),
+                Ref::new("SingleIdentifierGrammar").optional()
+            ]),
+            Sequence::new(vec_of_erased![
+                one_of(vec_of_erased![
+                    Ref::keyword("COMMIT"),
+                    Ref::keyword("ROLLBACK")
+                ]),
+                one_of(vec_of_erased![
+                    Ref::keyword("TRAN"),
+                    Ref::keyword("TRANSACTION"),
+                    Ref::keyword("WORK")
+                ])
+                .config(|this| this.optional()),
+                Ref::new("SingleIdentifierGrammar").optional()
+            ])
+        ])
+        .to_matchable(),
+    );
+
+    tsql
+}
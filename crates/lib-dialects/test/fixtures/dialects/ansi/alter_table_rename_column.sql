@@ -0,0 +1,2 @@
+ALTER TABLE x RENAME COLUMN y TO z;
+ALTER TABLE x RENAME CONSTRAINT x_pkey TO x_primary_key;
@@ -0,0 +1,8 @@
+CREATE SCHEMA AUTHORIZATION joe;
+
+CREATE SCHEMA hr AUTHORIZATION joe;
+
+CREATE SCHEMA hr AUTHORIZATION joe
+    CREATE TABLE staff (id INT, name VARCHAR(100))
+    GRANT SELECT ON staff TO public
+    CREATE VIEW staff_names AS SELECT name FROM staff;
@@ -0,0 +1,12 @@
+CREATE GLOBAL TEMPORARY TABLE session_totals (
+    id INT,
+    total INT
+) ON COMMIT PRESERVE ROWS;
+
+CREATE LOCAL TEMPORARY TABLE staging_rows (
+    id INT
+) ON COMMIT DELETE ROWS;
+
+CREATE TEMPORARY TABLE scratch (
+    id INT
+);
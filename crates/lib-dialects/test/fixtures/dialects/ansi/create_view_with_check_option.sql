@@ -0,0 +1,11 @@
+CREATE VIEW active_users AS
+SELECT id, name FROM users WHERE is_active
+WITH CHECK OPTION;
+
+CREATE VIEW active_users AS
+SELECT id, name FROM users WHERE is_active
+WITH CASCADED CHECK OPTION;
+
+CREATE OR REPLACE VIEW active_users (id, name) AS
+SELECT id, name FROM users WHERE is_active
+WITH LOCAL CHECK OPTION;
@@ -0,0 +1,7 @@
+SELECT region, product, GROUPING(region, product), SUM(amount)
+FROM sales
+GROUP BY GROUPING SETS ((region, product), (region), ());
+
+SELECT region, SUM(amount)
+FROM sales
+GROUP BY GROUPING SETS (ROLLUP (region), ());
@@ -0,0 +1,10 @@
+SELECT a
+FROM counter
+ORDER BY a
+OFFSET 10 ROWS;
+
+SELECT a
+FROM counter
+ORDER BY a
+OFFSET 1 ROW
+FETCH NEXT 5 ROWS ONLY;
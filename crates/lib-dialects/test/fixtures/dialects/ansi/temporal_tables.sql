@@ -0,0 +1,19 @@
+CREATE TABLE employees (
+    id INT,
+    valid_from TIMESTAMP,
+    valid_to TIMESTAMP,
+    PERIOD FOR SYSTEM_TIME (valid_from, valid_to)
+);
+
+CREATE TABLE contracts (
+    id INT,
+    starts_on DATE,
+    ends_on DATE,
+    PERIOD FOR contract_period (starts_on, ends_on)
+);
+
+SELECT id FROM employees FOR SYSTEM_TIME AS OF '2024-01-01';
+
+SELECT e.id FROM employees FOR SYSTEM_TIME BETWEEN '2024-01-01' AND '2024-06-30' AS e;
+
+SELECT id FROM employees FOR SYSTEM_TIME FROM '2024-01-01' TO '2024-06-30';
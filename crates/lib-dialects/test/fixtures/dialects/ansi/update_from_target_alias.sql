@@ -0,0 +1,5 @@
+UPDATE o
+SET o.status = c.status
+FROM orders AS o
+INNER JOIN customers AS c ON o.customer_id = c.id
+WHERE c.active = 1
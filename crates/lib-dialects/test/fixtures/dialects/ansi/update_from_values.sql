@@ -0,0 +1,4 @@
+UPDATE my_table
+SET label = v.label
+FROM (VALUES (1, 'one'), (2, 'two')) AS v (id, label)
+WHERE my_table.id = v.id
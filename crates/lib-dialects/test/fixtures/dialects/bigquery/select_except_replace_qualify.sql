@@ -0,0 +1,9 @@
+SELECT
+  * EXCEPT (foo) REPLACE (CONCAT(fruit, 'berry') AS fruit)
+FROM `project.dataset.fruits`
+QUALIFY rank <= 3;
+
+SELECT
+  col_1,
+  col_2
+FROM UNNEST(ARRAY<STRUCT<col_1 STRING, col_2 STRING>>[('hello', 'world')]);
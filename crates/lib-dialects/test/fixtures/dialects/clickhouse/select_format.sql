@@ -0,0 +1,3 @@
+SELECT id, name FROM users WHERE active = 1 FORMAT JSONEachRow;
+
+SELECT count() FROM events FORMAT CSV;
@@ -0,0 +1,3 @@
+CALL pragma_version();
+
+CALL pragma_table_info('tbl');
@@ -0,0 +1,10 @@
+SELECT
+    my_list[2],
+    my_list[1:3],
+    my_list[:3],
+    my_list[2:],
+    my_list[:],
+    my_list[1:10:2],
+    my_list[::2],
+    my_list[2:-1:1]
+FROM lists;
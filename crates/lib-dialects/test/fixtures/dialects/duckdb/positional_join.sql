@@ -0,0 +1,3 @@
+SELECT df1.*, df2.*
+FROM df1
+POSITIONAL JOIN df2;
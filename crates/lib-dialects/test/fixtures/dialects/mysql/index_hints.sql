@@ -0,0 +1,5 @@
+SELECT o.id
+FROM orders AS o USE INDEX (orders_status_idx)
+JOIN customers AS c FORCE INDEX FOR JOIN (customers_pkey) ON o.customer_id = c.id;
+
+SELECT id FROM orders IGNORE KEY FOR ORDER BY (orders_created_idx) ORDER BY created_at;
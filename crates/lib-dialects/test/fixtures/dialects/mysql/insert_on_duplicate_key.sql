@@ -0,0 +1,9 @@
+INSERT INTO inventory (sku, qty) VALUES ('a-1', 5)
+ON DUPLICATE KEY UPDATE qty = qty + 5;
+
+INSERT INTO inventory (sku, qty) VALUES ('a-2', 3)
+ON DUPLICATE KEY UPDATE sku = VALUES(sku), qty = qty + 1;
+
+REPLACE INTO inventory (sku, qty) VALUES ('a-1', 5);
+
+INSERT IGNORE INTO inventory SET sku = 'a-2', qty = 1;
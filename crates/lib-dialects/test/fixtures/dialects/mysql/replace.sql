@@ -0,0 +1,4 @@
+REPLACE inventory SET sku = 'a-1', qty = 5;
+
+REPLACE LOW_PRIORITY INTO inventory (sku, qty)
+SELECT sku, qty FROM staged_inventory;
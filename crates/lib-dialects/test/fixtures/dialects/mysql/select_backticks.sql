@@ -0,0 +1 @@
+SELECT `id`, `order date` FROM `shop`.`orders` WHERE status = "open" LIMIT 10, 20;
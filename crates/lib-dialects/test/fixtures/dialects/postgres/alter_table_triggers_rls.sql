@@ -0,0 +1,31 @@
+ALTER TABLE accounts ENABLE TRIGGER audit_accounts;
+
+ALTER TABLE accounts DISABLE TRIGGER audit_accounts;
+
+ALTER TABLE accounts DISABLE TRIGGER ALL;
+
+ALTER TABLE accounts ENABLE TRIGGER USER;
+
+ALTER TABLE accounts ENABLE REPLICA TRIGGER audit_accounts;
+
+ALTER TABLE accounts ENABLE ALWAYS TRIGGER audit_accounts;
+
+ALTER TABLE accounts ENABLE RULE protect_accounts;
+
+ALTER TABLE accounts DISABLE RULE protect_accounts;
+
+ALTER TABLE accounts ENABLE REPLICA RULE protect_accounts;
+
+ALTER TABLE accounts ENABLE ALWAYS RULE protect_accounts;
+
+ALTER TABLE accounts ENABLE ROW LEVEL SECURITY;
+
+ALTER TABLE accounts DISABLE ROW LEVEL SECURITY;
+
+ALTER TABLE accounts FORCE ROW LEVEL SECURITY;
+
+ALTER TABLE accounts NO FORCE ROW LEVEL SECURITY;
+
+ALTER TABLE IF EXISTS ONLY accounts
+    ENABLE ROW LEVEL SECURITY,
+    FORCE ROW LEVEL SECURITY;
@@ -0,0 +1,9 @@
+SELECT ts AT TIME ZONE 'UTC' AT TIME ZONE 'America/New_York' FROM t;
+
+SELECT ts::timestamp AT TIME ZONE 'UTC' FROM t;
+
+SELECT ts::timestamptz AT TIME ZONE 'UTC' AT TIME ZONE 'EST' AS local_ts FROM t;
+
+SELECT (ts AT TIME ZONE 'UTC')::date FROM t;
+
+SELECT ts AT TIME ZONE tz_name AT TIME ZONE 'UTC', created_at AT TIME ZONE 'UTC' + INTERVAL '1 day' FROM t;
@@ -0,0 +1,5 @@
+SELECT name FROM users ORDER BY name COLLATE "C";
+
+SELECT * FROM users WHERE first_name = last_name COLLATE "C";
+
+SELECT * FROM users WHERE name COLLATE pg_catalog."default" = 'alice';
@@ -0,0 +1,26 @@
+CREATE OR REPLACE FUNCTION add_tax(amount numeric)
+RETURNS numeric
+SECURITY DEFINER
+STABLE
+STRICT
+COST 10
+LANGUAGE sql
+AS $$ SELECT amount * 1.2 $$;
+
+CREATE FUNCTION list_orders(customer_id integer)
+RETURNS SETOF orders
+AS $$ SELECT * FROM orders WHERE orders.customer_id = $1 $$
+LANGUAGE sql
+ROWS 100
+IMMUTABLE
+SECURITY INVOKER
+PARALLEL SAFE;
+
+CREATE OR REPLACE PROCEDURE archive_orders(cutoff date)
+LANGUAGE plpgsql
+SECURITY DEFINER
+AS $$
+BEGIN
+    DELETE FROM orders WHERE created_at < cutoff;
+END;
+$$;
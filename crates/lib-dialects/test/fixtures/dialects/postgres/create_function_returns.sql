@@ -0,0 +1,8 @@
+CREATE FUNCTION recent_orders(int)
+RETURNS TABLE (id bigint, created_at timestamp with time zone, tags text[])
+AS $$ SELECT id, created_at, tags FROM orders LIMIT $1 $$
+LANGUAGE sql;
+
+CREATE FUNCTION all_orders() RETURNS SETOF public.orders
+AS $$ SELECT * FROM public.orders $$
+LANGUAGE sql;
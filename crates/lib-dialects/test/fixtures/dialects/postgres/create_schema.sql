@@ -9,3 +9,7 @@ CREATE SCHEMA AUTHORIZATION bob;
 CREATE SCHEMA IF NOT EXISTS asdf AUTHORIZATION bob;
 
 CREATE SCHEMA IF NOT EXISTS AUTHORIZATION bob;
+
+CREATE SCHEMA hollywood
+    CREATE TABLE films (title text)
+    CREATE INDEX title_idx ON films (title);
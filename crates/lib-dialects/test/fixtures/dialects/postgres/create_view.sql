@@ -65,3 +65,7 @@ WHERE "parent_id" = "node_id";
 
 -- use of collation as non-reserved keyword
 create view foo as select col1 as collation from OTHER_VIEW;
+
+CREATE VIEW active_users AS
+SELECT id FROM users WHERE is_active
+WITH CHECK OPTION;
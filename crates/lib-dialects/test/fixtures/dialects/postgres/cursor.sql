@@ -0,0 +1,31 @@
+BEGIN;
+
+DECLARE c CURSOR FOR SELECT id, name FROM users;
+
+DECLARE c2 BINARY INSENSITIVE NO SCROLL CURSOR WITH HOLD FOR SELECT * FROM orders;
+
+DECLARE c3 SCROLL CURSOR WITHOUT HOLD FOR SELECT 1;
+
+FETCH NEXT FROM c;
+
+FETCH 5 FROM c;
+
+FETCH PRIOR IN c;
+
+FETCH ABSOLUTE -1 FROM c;
+
+FETCH RELATIVE 2 FROM c;
+
+FETCH FORWARD ALL FROM c;
+
+FETCH BACKWARD 3 FROM c;
+
+FETCH ALL IN c;
+
+FETCH c;
+
+CLOSE c;
+
+CLOSE ALL;
+
+COMMIT;
@@ -0,0 +1,25 @@
+CREATE TABLE events (
+    a TIMESTAMP,
+    b TIMESTAMP(6),
+    c TIMESTAMP WITH TIME ZONE,
+    d TIMESTAMP(6) WITH TIME ZONE,
+    e TIMESTAMP WITHOUT TIME ZONE,
+    f TIMESTAMP(3) WITHOUT TIME ZONE,
+    g TIME,
+    h TIME(3),
+    i TIME WITH TIME ZONE,
+    j TIME(3) WITH TIME ZONE,
+    k TIME WITHOUT TIME ZONE,
+    l TIME(0) WITHOUT TIME ZONE,
+    m TIMESTAMPTZ,
+    n TIMESTAMPTZ(6),
+    o TIMETZ,
+    p TIMETZ(3)
+);
+
+SELECT
+    CAST(x AS TIMESTAMP(6) WITH TIME ZONE),
+    x::TIMESTAMPTZ,
+    x::TIMETZ(3),
+    y::TIME(3) WITHOUT TIME ZONE
+FROM t;
@@ -0,0 +1,6 @@
+select o.id, t.v, t.n
+from orders as o
+left join lateral unnest(o.tags) with ordinality as t(v, n) on true;
+
+select o.id, t.v, t.n
+from orders as o, lateral unnest(o.tags) with ordinality as t(v, n);
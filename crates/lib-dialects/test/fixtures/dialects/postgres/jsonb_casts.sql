@@ -0,0 +1,3 @@
+SELECT payload::jsonb ->> 'status' AS status
+FROM events
+WHERE payload::jsonb @> '{"kind": "order"}';
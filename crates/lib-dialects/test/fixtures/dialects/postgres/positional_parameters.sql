@@ -0,0 +1,5 @@
+SELECT id, name FROM users WHERE id = $1 AND status = $2;
+
+INSERT INTO users (id, name) VALUES ($1, $2);
+
+SELECT $body$ $1 $body$, $$ $2 $$;
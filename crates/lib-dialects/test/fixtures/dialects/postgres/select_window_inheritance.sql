@@ -0,0 +1,10 @@
+SELECT
+    SUM(amount) OVER w1 AS running_total,
+    RANK() OVER w2 AS ranked,
+    AVG(amount) OVER w3 AS moving_average,
+    COUNT(*) OVER (w1 ORDER BY created_at) AS inline_extension
+FROM payments
+WINDOW
+    w1 AS (PARTITION BY customer_id),
+    w2 AS (w1 ORDER BY amount DESC),
+    w3 AS (w2 ROWS BETWEEN 2 PRECEDING AND CURRENT ROW);
@@ -0,0 +1,5 @@
+UPDATE orders AS o
+SET status = c.status
+FROM customers AS c
+INNER JOIN regions AS r ON c.region_id = r.id
+WHERE o.customer_id = c.id AND r.code = 'EU';
@@ -0,0 +1,4 @@
+UPDATE my_table AS t
+SET label = v.label, amount = v.amount
+FROM (VALUES (1, 'one', 10), (2, 'two', 20)) AS v (id, label, amount)
+WHERE t.id = v.id;
@@ -0,0 +1,24 @@
+WITH moved_rows AS (
+    DELETE FROM products
+    WHERE date >= '2010-10-01' AND date < '2010-11-01'
+    RETURNING *
+)
+INSERT INTO products_log
+SELECT * FROM moved_rows;
+
+WITH updated AS (
+    UPDATE products SET price = price * 1.05
+    WHERE category = 'books'
+    RETURNING id, price
+)
+SELECT id, price FROM updated;
+
+WITH deleted AS (
+    DELETE FROM orders WHERE status = 'void' RETURNING id
+),
+archived AS (
+    INSERT INTO orders_archive (id)
+    SELECT id FROM deleted
+    RETURNING id
+)
+SELECT count(*) FROM archived;
@@ -0,0 +1,11 @@
+CREATE TABLE t1 (
+    col1 INTEGER,
+    col2 VARCHAR(5)
+)
+DISTSTYLE KEY
+DISTKEY(col1)
+COMPOUND SORTKEY(col2, col1);
+
+COPY t1
+FROM 's3://mybucket/data/t1/'
+IAM_ROLE 'arn:aws:iam::0123456789012:role/MyRedshiftRole';
@@ -0,0 +1,8 @@
+declare profit number(38, 2) default 0.0;
+declare revenue number(38, 2);
+declare c1 cursor for select price from invoices;
+declare res resultset default (select price from invoices);
+begin
+  profit := revenue - 100;
+  return profit;
+end;
@@ -0,0 +1,6 @@
+merge into t1 using t2 on t1.t1key = t2.t2key
+    when matched then update set t1.val = t2.val where t2.active
+    when matched then delete where t2.marked = 1;
+
+merge into t1 using t2 on t1.t1key = t2.t2key
+    when not matched by target then insert (val) values (t2.val);
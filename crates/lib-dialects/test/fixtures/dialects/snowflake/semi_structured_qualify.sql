@@ -0,0 +1,6 @@
+SELECT
+    ticket_id,
+    value:thing.foo AS another_val
+FROM raw_tickets, lateral flatten(INPUT => custom_fields)
+WHERE ticket_id ILIKE ANY ('%an%', '%an')
+QUALIFY row_number() OVER (PARTITION BY ticket_id ORDER BY ticket_id) = 1;
@@ -0,0 +1,22 @@
+CREATE TABLE people (
+    id INT,
+    gender STRING
+)
+USING DELTA
+PARTITIONED BY (gender);
+
+SELECT
+    `id`,
+    c_age
+FROM people
+    LATERAL VIEW EXPLODE(ARRAY(30, 60)) AS c_age
+DISTRIBUTE BY
+    c_age;
+
+SELECT
+    `id`,
+    c_age
+FROM people
+    LATERAL VIEW EXPLODE(ARRAY(30, 60)) AS c_age
+CLUSTER BY
+    c_age;
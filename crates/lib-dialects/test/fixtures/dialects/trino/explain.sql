@@ -0,0 +1,11 @@
+EXPLAIN SELECT * FROM orders;
+
+EXPLAIN ANALYZE SELECT count(*) FROM orders;
+
+EXPLAIN ANALYZE VERBOSE SELECT count(*) FROM orders WHERE orderkey > 10;
+
+EXPLAIN (FORMAT GRAPHVIZ) SELECT regionkey, count(*) FROM nation GROUP BY 1;
+
+EXPLAIN (TYPE DISTRIBUTED, FORMAT JSON) SELECT regionkey FROM nation;
+
+EXPLAIN (TYPE IO, FORMAT JSON) SELECT * FROM orders WHERE orderdate > DATE '2024-01-01';
@@ -0,0 +1 @@
+SELECT REDUCE(numbers, 0, (s, x) -> s + x, s -> s);
@@ -0,0 +1,5 @@
+SHOW CATALOGS;
+
+SHOW SCHEMAS FROM hive LIKE 'sales%';
+
+SHOW TABLES IN hive.sales;
@@ -0,0 +1 @@
+SELECT DECIMAL '1.2', REAL '10.3', JSON '{"a": 1}';
@@ -0,0 +1,2 @@
+SELECT t.x, t.n
+FROM UNNEST(numbers) WITH ORDINALITY AS t (x, n);
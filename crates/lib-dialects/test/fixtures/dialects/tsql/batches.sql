@@ -0,0 +1,8 @@
+INSERT INTO orders (id) VALUES (1);
+GO
+
+INSERT INTO orders (id) VALUES (2)
+  go 2
+
+SELECT id AS go FROM orders
+GO -- done
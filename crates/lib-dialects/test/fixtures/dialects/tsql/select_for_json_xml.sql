@@ -0,0 +1,10 @@
+SELECT id, name
+FROM customers
+ORDER BY id
+FOR JSON PATH, ROOT('customers'), INCLUDE_NULL_VALUES;
+
+SELECT name FROM customers WHERE id = 1 FOR JSON AUTO, WITHOUT_ARRAY_WRAPPER;
+
+SELECT id FROM customers FOR XML RAW('customer'), ROOT('customers'), ELEMENTS XSINIL;
+
+SELECT name FROM customers FOR XML PATH(''), TYPE;
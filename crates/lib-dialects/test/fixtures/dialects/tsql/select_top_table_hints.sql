@@ -0,0 +1,3 @@
+SELECT TOP 10 [o].[id], [o].[total]
+FROM [dbo].[orders] AS [o] WITH (NOLOCK)
+WHERE [o].[status] = 'open';
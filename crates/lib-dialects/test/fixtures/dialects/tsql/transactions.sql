@@ -0,0 +1,4 @@
+BEGIN TRAN;
+DELETE FROM orders WHERE id = 1;
+COMMIT TRANSACTION;
+GO
@@ -0,0 +1,7 @@
+DECLARE @threshold INT = 100;
+
+BEGIN
+    SET @threshold = @threshold * 2;
+    SELECT id FROM orders WHERE total > @threshold;
+END
+GO
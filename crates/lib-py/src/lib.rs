@@ -0,0 +1,226 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use sqruff_lib::api::simple::get_simple_config;
+use sqruff_lib::core::linter::core::Linter;
+use sqruff_lib_core::errors::{SQLBaseError, SQLFluffUserError};
+use sqruff_lib_core::parser::segments::base::Tables;
+
+/// A violation found by `lint`, with the same fields as the records returned
+/// by sqlfluff.
+#[pyclass(frozen, get_all, module = "sqruff_py")]
+#[derive(Clone)]
+pub struct Violation {
+    code: String,
+    name: String,
+    description: String,
+    line_no: usize,
+    line_pos: usize,
+    fixable: bool,
+}
+
+#[pymethods]
+impl Violation {
+    fn __repr__(&self) -> String {
+        format!(
+            "Violation(code={:?}, line_no={}, line_pos={}, description={:?})",
+            self.code, self.line_no, self.line_pos, self.description
+        )
+    }
+}
+
+impl From<SQLBaseError> for Violation {
+    fn from(violation: SQLBaseError) -> Self {
+        Violation {
+            code: violation.rule_code().to_string(),
+            name: violation
+                .rule
+                .as_ref()
+                .map_or("", |rule| rule.name)
+                .to_string(),
+            description: violation.description,
+            line_no: violation.line_no,
+            line_pos: violation.line_pos,
+            fixable: violation.fixable,
+        }
+    }
+}
+
+fn user_error(error: SQLFluffUserError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+fn linter(
+    dialect: Option<String>,
+    rules: Option<Vec<String>>,
+    exclude_rules: Option<Vec<String>>,
+    config_path: Option<String>,
+) -> PyResult<Linter> {
+    let config =
+        get_simple_config(dialect, rules, exclude_rules, config_path).map_err(user_error)?;
+    Ok(Linter::new(config, None, None, false))
+}
+
+/// Lints a SQL string and returns the violations found.
+#[pyfunction]
+#[pyo3(signature = (sql, dialect = None, rules = None, exclude_rules = None, config_path = None))]
+fn lint(
+    sql: &str,
+    dialect: Option<String>,
+    rules: Option<Vec<String>>,
+    exclude_rules: Option<Vec<String>>,
+    config_path: Option<String>,
+) -> PyResult<Vec<Violation>> {
+    let linter = linter(dialect, rules, exclude_rules, config_path)?;
+    let linted = linter.lint_string(sql, None, false);
+
+    Ok(linted.violations.into_iter().map(Violation::from).collect())
+}
+
+/// Fixes a SQL string and returns the fixed string.
+#[pyfunction]
+#[pyo3(signature = (sql, dialect = None, rules = None, exclude_rules = None, config_path = None))]
+fn fix(
+    sql: &str,
+    dialect: Option<String>,
+    rules: Option<Vec<String>>,
+    exclude_rules: Option<Vec<String>>,
+    config_path: Option<String>,
+) -> PyResult<String> {
+    let linter = linter(dialect, rules, exclude_rules, config_path)?;
+
+    Ok(linter.lint_string(sql, None, true).fix_string())
+}
+
+/// Parses a SQL string and returns the parse tree as nested dicts and lists,
+/// raising a `ValueError` if part of it can't be parsed.
+#[pyfunction]
+#[pyo3(signature = (sql, dialect = None, config_path = None))]
+fn parse(
+    py: Python<'_>,
+    sql: &str,
+    dialect: Option<String>,
+    config_path: Option<String>,
+) -> PyResult<PyObject> {
+    let linter = linter(dialect, None, None, config_path)?;
+    let tables = Tables::default();
+    let parsed = linter
+        .parse_string(&tables, sql, None)
+        .map_err(user_error)?;
+
+    if let Some(violation) = parsed.violations.first() {
+        return Err(PyValueError::new_err(format!(
+            "Found unparsable section on line {}: {}",
+            violation.line_no, violation.description
+        )));
+    }
+
+    let tree = parsed.tree.map(|tree| tree.to_serialised(false, true));
+    let json =
+        serde_json::to_string(&tree).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+    Ok(py.import("json")?.call_method1("loads", (json,))?.unbind())
+}
+
+#[pymodule]
+fn sqruff_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Violation>()?;
+    m.add_function(wrap_pyfunction!(lint, m)?)?;
+    m.add_function(wrap_pyfunction!(fix, m)?)?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::types::PyDict;
+
+    use super::*;
+
+    fn rules(rules: &[&str]) -> Option<Vec<String>> {
+        Some(rules.iter().map(|rule| rule.to_string()).collect())
+    }
+
+    #[test]
+    fn test_violation_from_error() {
+        let violation = Violation::from(SQLBaseError {
+            description: "Unparsable section".into(),
+            line_no: 2,
+            line_pos: 3,
+            ..Default::default()
+        });
+
+        assert_eq!(violation.code, "????");
+        assert_eq!(violation.name, "");
+        assert_eq!(violation.description, "Unparsable section");
+        assert_eq!((violation.line_no, violation.line_pos), (2, 3));
+        assert!(!violation.fixable);
+    }
+
+    #[test]
+    fn test_lint() {
+        let violations = lint(
+            "SELECT 1  AS a\n",
+            Some("ansi".into()),
+            rules(&["LT01"]),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations.len(), 1);
+        let violation = &violations[0];
+        assert_eq!(violation.code, "LT01");
+        assert_eq!(violation.name, "layout.spacing");
+        assert_eq!((violation.line_no, violation.line_pos), (1, 9));
+        assert!(violation.fixable);
+    }
+
+    #[test]
+    fn test_fix() {
+        let fixed = fix(
+            "SELECT 1  AS a\n",
+            Some("ansi".into()),
+            None,
+            rules(&["AL04"]),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(fixed, "SELECT 1 AS a\n");
+    }
+
+    #[test]
+    fn test_invalid_dialect() {
+        Python::with_gil(|py| {
+            let Err(err) = lint("SELECT 1\n", Some("not_a_dialect".into()), None, None, None)
+            else {
+                panic!("expected an unknown dialect to be an error");
+            };
+
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_parse() {
+        Python::with_gil(|py| {
+            let tree = parse(py, "SELECT 1\n", Some("ansi".into()), None).unwrap();
+            let tree = tree.bind(py).downcast::<PyDict>().unwrap();
+
+            assert!(tree.contains("file").unwrap());
+        });
+    }
+
+    #[test]
+    fn test_parse_unparsable() {
+        Python::with_gil(|py| {
+            let err = parse(py, "SelEc", Some("ansi".into()), None).unwrap_err();
+
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert_eq!(
+                err.value(py).to_string(),
+                "Found unparsable section on line 1: Unparsable section"
+            );
+        });
+    }
+}
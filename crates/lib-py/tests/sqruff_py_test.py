@@ -0,0 +1,38 @@
+"""Smoke tests for the sqruff_py bindings, run once they are built with maturin."""
+
+import pytest
+
+sqruff_py = pytest.importorskip("sqruff_py")
+
+
+def test_lint():
+    violations = sqruff_py.lint("SELECT 1  AS a\n", dialect="ansi", rules=["LT01"])
+
+    assert len(violations) == 1
+    violation = violations[0]
+    assert violation.code == "LT01"
+    assert violation.name == "layout.spacing"
+    assert (violation.line_no, violation.line_pos) == (1, 9)
+    assert violation.fixable
+    assert isinstance(violation.description, str)
+
+
+def test_fix():
+    fixed = sqruff_py.fix("SELECT 1  AS a\n", dialect="ansi", rules=["LT01"])
+
+    assert fixed == "SELECT 1 AS a\n"
+
+
+def test_parse():
+    tree = sqruff_py.parse("SELECT 1\n", dialect="ansi")
+
+    assert isinstance(tree, dict)
+    assert "file" in tree
+
+
+def test_errors():
+    with pytest.raises(ValueError):
+        sqruff_py.lint("SELECT 1\n", dialect="not_a_dialect")
+
+    with pytest.raises(ValueError, match="Found unparsable section on line 1"):
+        sqruff_py.parse("SelEc", dialect="ansi")
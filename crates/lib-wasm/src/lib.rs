@@ -1,8 +1,11 @@
 use ahash::AHashMap;
 use line_index::LineIndex;
 use lineage::{Lineage, Node};
+use serde::Serialize;
 use sqruff_lib::core::config::FluffConfig;
 use sqruff_lib::core::linter::core::Linter as SqruffLinter;
+use sqruff_lib::core::linter::linted_file::LintedFile;
+use sqruff_lib_core::errors::SQLFluffUserError;
 use sqruff_lib_core::parser::parser::Parser;
 use sqruff_lib_core::parser::segments::base::Tables;
 use wasm_bindgen::prelude::*;
@@ -126,6 +129,96 @@ impl Linter {
     }
 }
 
+#[derive(Serialize)]
+struct JsonDiagnostic {
+    code: &'static str,
+    name: &'static str,
+    message: String,
+    fixable: bool,
+    start_line_number: u32,
+    start_column: u32,
+    end_line_number: u32,
+    end_column: u32,
+}
+
+#[derive(Serialize)]
+struct JsonFix {
+    sql: String,
+    diagnostics: Vec<JsonDiagnostic>,
+}
+
+/// Lints `sql` with a config given as JSON, see `FluffConfig::from_json`, and
+/// returns the violations as a JSON array.
+#[wasm_bindgen]
+pub fn lint(sql: &str, config_json: &str) -> std::result::Result<String, JsError> {
+    lint_json(sql, config_json).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// Fixes `sql` with a config given as JSON and returns an object holding the
+/// fixed `sql` and the `diagnostics` found in the original.
+#[wasm_bindgen]
+pub fn fix(sql: &str, config_json: &str) -> std::result::Result<String, JsError> {
+    fix_json(sql, config_json).map_err(|err| JsError::new(&err.to_string()))
+}
+
+fn lint_json(sql: &str, config_json: &str) -> std::result::Result<String, SQLFluffUserError> {
+    let linted = lint_with_json_config(sql, config_json, false)?;
+    let diagnostics = json_diagnostics(sql, &linted);
+
+    Ok(serde_json::to_string(&diagnostics).unwrap())
+}
+
+fn fix_json(sql: &str, config_json: &str) -> std::result::Result<String, SQLFluffUserError> {
+    let linted = lint_with_json_config(sql, config_json, true)?;
+    let diagnostics = json_diagnostics(sql, &linted);
+
+    Ok(serde_json::to_string(&JsonFix {
+        sql: linted.fix_string(),
+        diagnostics,
+    })
+    .unwrap())
+}
+
+fn lint_with_json_config(
+    sql: &str,
+    config_json: &str,
+    fix: bool,
+) -> std::result::Result<LintedFile, SQLFluffUserError> {
+    let config_json = if config_json.trim().is_empty() {
+        "{}"
+    } else {
+        config_json
+    };
+    let config = FluffConfig::from_json(config_json)?;
+    let linter = SqruffLinter::new(config, None, None, false);
+
+    Ok(linter.lint_string(sql, None, fix))
+}
+
+fn json_diagnostics(sql: &str, linted: &LintedFile) -> Vec<JsonDiagnostic> {
+    let line_index = LineIndex::new(sql);
+
+    linted
+        .violations
+        .iter()
+        .map(|violation| {
+            let start = line_index.line_col(violation.source_slice.start.try_into().unwrap());
+            let end = line_index.line_col(violation.source_slice.end.try_into().unwrap());
+
+            JsonDiagnostic {
+                code: violation.rule_code(),
+                name: violation.rule.as_ref().map_or("", |rule| rule.name),
+                message: violation.description.clone(),
+                fixable: violation.fixable,
+                start_line_number: start.line + 1,
+                start_column: start.col + 1,
+                end_line_number: end.line + 1,
+                end_column: end.col + 1,
+            }
+        })
+        .collect()
+}
+
 fn print_tree(
     tables: &lineage::ir::Tables,
     node: Node,
@@ -185,3 +278,71 @@ fn print_tree(
 
     string
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const CONFIG: &str = r#"{"core": {"dialect": "ansi", "rules": "AL04,LT01"}}"#;
+
+    #[test]
+    fn test_lint() {
+        let mut diagnostics: Value =
+            serde_json::from_str(&lint("SELECT 1  AS a\n", CONFIG).unwrap()).unwrap();
+
+        let diagnostic = diagnostics[0].as_object_mut().unwrap();
+        assert!(diagnostic.remove("message").unwrap().is_string());
+        assert_eq!(
+            diagnostics,
+            json!([{
+                "code": "LT01",
+                "name": "layout.spacing",
+                "fixable": true,
+                "start_line_number": 1,
+                "start_column": 9,
+                "end_line_number": 1,
+                "end_column": 11,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_lint_unfixable() {
+        let diagnostics: Value =
+            serde_json::from_str(&lint("SELECT t.a FROM foo AS t, bar AS t\n", CONFIG).unwrap())
+                .unwrap();
+
+        let diagnostics = diagnostics.as_array().unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["code"], "AL04");
+        assert_eq!(diagnostics[0]["fixable"], false);
+    }
+
+    #[test]
+    fn test_fix() {
+        let fixed: Value = serde_json::from_str(&fix("SELECT 1  AS a\n", CONFIG).unwrap()).unwrap();
+
+        assert_eq!(fixed["sql"], "SELECT 1 AS a\n");
+        let diagnostics = fixed["diagnostics"].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["code"], "LT01");
+    }
+
+    #[test]
+    fn test_empty_config() {
+        let diagnostics: Value = serde_json::from_str(&lint("SELECT 1\n", "").unwrap()).unwrap();
+
+        assert_eq!(diagnostics, json!([]));
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let err = lint_json("SELECT 1\n", "{\"core\":").unwrap_err();
+        assert!(err.value.starts_with("Invalid JSON config: "), "{err}");
+
+        let err = fix_json("SELECT 1\n", "[]").unwrap_err();
+        assert!(err.value.starts_with("Invalid JSON config: "), "{err}");
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use ahash::AHashMap;
 use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::registry::DialectRegistry;
 use sqruff_lib_core::errors::{SQLBaseError, SQLFluffUserError};
 
 use crate::cli::formatters::Formatter;
@@ -18,8 +19,10 @@ pub fn get_simple_config(
 ) -> Result<FluffConfig, SQLFluffUserError> {
     let mut overrides = AHashMap::new();
     if let Some(dialect) = dialect {
-        DialectKind::from_str(dialect.as_str())
-            .map_err(|error| SQLFluffUserError::new(error.to_string()))?;
+        if !DialectRegistry::contains(&dialect) {
+            DialectKind::from_str(dialect.as_str())
+                .map_err(|error| SQLFluffUserError::new(error.to_string()))?;
+        }
         overrides.insert("dialect".to_owned(), dialect);
     }
     if let Some(rules) = rules {
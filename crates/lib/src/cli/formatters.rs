@@ -55,6 +55,12 @@ pub trait Formatter: Send + Sync {
     fn has_fail(&self) -> bool;
 
     fn completion_message(&self);
+
+    /// Whether the reported files should carry the patches fixing their
+    /// violations, even when they are only being linted.
+    fn needs_fixes(&self) -> bool {
+        false
+    }
 }
 
 pub struct OutputStreamFormatter {
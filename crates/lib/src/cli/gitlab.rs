@@ -0,0 +1,201 @@
+//! Output in the [Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#code-quality-report-format)
+//! format used by GitLab, a subset of the Code Climate format.
+
+use std::sync::Mutex;
+
+use ahash::AHashMap;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sqruff_lib_core::errors::SQLBaseError;
+
+use super::formatters::Formatter;
+use crate::core::config::FluffConfig;
+use crate::core::linter::linted_file::LintedFile;
+
+#[derive(Default)]
+pub struct GitlabFormatter {
+    issues: Mutex<Vec<Issue>>,
+}
+
+impl Formatter for GitlabFormatter {
+    fn dispatch_file_violations(&self, linted_file: &LintedFile, only_fixable: bool) {
+        let violations = linted_file.get_violations(only_fixable.then_some(true));
+        let mut occurrences = AHashMap::new();
+        let mut issues = self.issues.lock().unwrap();
+        issues.extend(
+            violations
+                .into_iter()
+                .map(|violation| Issue::new(linted_file, violation, &mut occurrences)),
+        );
+    }
+
+    fn has_fail(&self) -> bool {
+        let issues = self.issues.lock().unwrap();
+        issues.iter().any(|issue| issue.severity == Severity::Major)
+    }
+
+    fn completion_message(&self) {
+        let issues = self.issues.lock().unwrap();
+        println!("{}", serde_json::to_string(&*issues).unwrap());
+    }
+
+    fn dispatch_template_header(
+        &self,
+        _f_name: String,
+        _linter_config: FluffConfig,
+        _file_config: FluffConfig,
+    ) {
+    }
+
+    fn dispatch_parse_header(&self, _f_name: String) {}
+}
+
+#[derive(Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum Severity {
+    Minor,
+    Major,
+}
+
+#[derive(Serialize)]
+struct Issue {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: Severity,
+    location: Location,
+}
+
+impl Issue {
+    /// `occurrences` counts the issues of the file seen so far by their
+    /// content.
+    fn new(
+        linted_file: &LintedFile,
+        violation: SQLBaseError,
+        occurrences: &mut AHashMap<Vec<u8>, u64>,
+    ) -> Self {
+        let path = linted_file.path.replace('\\', "/");
+        let check_name = violation.rule_code().to_string();
+        let source = &linted_file.templated_file.source_str;
+        let offending = source
+            .get(violation.source_slice.clone())
+            .unwrap_or_default();
+
+        // GitLab uses the fingerprint to tell which issues are new in a merge
+        // request. It leaves out the position of the issue, so that editing
+        // the lines above it doesn't make it look new.
+        let mut hasher = Sha256::new();
+        for part in [
+            path.as_str(),
+            check_name.as_str(),
+            violation.description.as_str(),
+            offending,
+        ] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        // GitLab expects unique fingerprints, so identical issues in a file,
+        // such as two `SELECT *`, are told apart by the order they come in.
+        let occurrence = occurrences
+            .entry(hasher.clone().finalize().to_vec())
+            .or_default();
+        hasher.update(occurrence.to_le_bytes());
+        *occurrence += 1;
+        let fingerprint = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        Issue {
+            description: violation.description,
+            check_name,
+            fingerprint,
+            severity: if violation.warning {
+                Severity::Minor
+            } else {
+                Severity::Major
+            },
+            location: Location {
+                path,
+                lines: Lines {
+                    begin: violation.line_no,
+                },
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Location {
+    path: String,
+    lines: Lines,
+}
+
+#[derive(Serialize)]
+struct Lines {
+    begin: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ahash::AHashSet;
+
+    use super::*;
+    use crate::core::linter::core::Linter;
+
+    #[test]
+    fn test_gitlab_code_quality() {
+        let formatter = Arc::new(GitlabFormatter::default());
+        let config = FluffConfig::from_source("[sqruff]\nrules = LT01\n", None);
+        let linter = Linter::new(
+            config,
+            Some(formatter.clone() as Arc<dyn Formatter>),
+            None,
+            false,
+        );
+
+        linter.lint_string("SELECT\n    1 ,4\n", Some("query.sql".into()), false);
+        let issues = serde_json::to_value(&*formatter.issues.lock().unwrap()).unwrap();
+        let issues = issues.as_array().unwrap();
+
+        assert_eq!(issues[0]["check_name"], "LT01");
+        assert_eq!(issues[0]["severity"], "major");
+        assert_eq!(issues[0]["location"]["path"], "query.sql");
+        assert_eq!(issues[0]["location"]["lines"]["begin"], 2);
+        assert_ne!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+        assert!(formatter.has_fail());
+
+        // Moving the issue around doesn't change its fingerprint.
+        let fingerprint = issues[1]["fingerprint"].clone();
+        formatter.issues.lock().unwrap().clear();
+        linter.lint_string("\nSELECT\n    1 ,4\n", Some("query.sql".into()), false);
+        let issues = serde_json::to_value(&*formatter.issues.lock().unwrap()).unwrap();
+        assert_eq!(issues[1]["location"]["lines"]["begin"], 3);
+        assert_eq!(issues[1]["fingerprint"], fingerprint);
+    }
+
+    #[test]
+    fn test_gitlab_code_quality_identical_issues() {
+        let formatter = Arc::new(GitlabFormatter::default());
+        let config = FluffConfig::from_source("[sqruff]\nrules = LT01\n", None);
+        let linter = Linter::new(
+            config,
+            Some(formatter.clone() as Arc<dyn Formatter>),
+            None,
+            false,
+        );
+
+        // Both commas have the same whitespace in front of them.
+        linter.lint_string("SELECT\n    1 ,2 ,3\n", Some("query.sql".into()), false);
+        let issues = formatter.issues.lock().unwrap();
+
+        let descriptions: AHashSet<_> = issues.iter().map(|issue| &issue.description).collect();
+        assert!(descriptions.len() < issues.len());
+
+        let fingerprints: AHashSet<_> = issues.iter().map(|issue| &issue.fingerprint).collect();
+        assert_eq!(fingerprints.len(), issues.len());
+    }
+}
@@ -18,8 +18,8 @@ impl Formatter for JsonFormatter {
         let mut lock = self.violations.lock().unwrap();
         lock.entry(linted_file.path.clone()).or_default().extend(
             violations
-                .iter()
-                .map(|err| Diagnostic::from(err.clone()))
+                .into_iter()
+                .map(|err| Diagnostic::new(err, &linted_file.templated_file))
                 .collect::<Vec<_>>(),
         );
     }
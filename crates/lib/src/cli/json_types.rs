@@ -2,15 +2,22 @@ use std::collections::BTreeMap;
 
 use serde::Serialize;
 use sqruff_lib_core::errors::SQLBaseError;
+use sqruff_lib_core::templaters::base::TemplatedFile;
 
-impl From<SQLBaseError> for Diagnostic {
-    fn from(value: SQLBaseError) -> Self {
+impl Diagnostic {
+    /// Creates a diagnostic for a violation found in `templated_file`.
+    pub fn new(value: SQLBaseError, templated_file: &TemplatedFile) -> Self {
         let code = value.rule.map(|rule| rule.code.to_string());
+        let start = Position::new(value.line_no as u32, value.line_pos as u32);
+        let end = if value.source_slice.is_empty() {
+            Position::new(value.line_no as u32, value.line_pos as u32)
+        } else {
+            let (line, character) =
+                templated_file.get_line_pos_of_char_pos(value.source_slice.end, true);
+            Position::new(line as u32, character as u32)
+        };
         Diagnostic {
-            range: Range {
-                start: Position::new(value.line_no as u32, value.line_pos as u32),
-                end: Position::new(value.line_no as u32, value.line_pos as u32),
-            },
+            range: Range { start, end },
             message: value.description,
             severity: if value.warning {
                 DiagnosticSeverity::Warning
@@ -19,6 +26,7 @@ impl From<SQLBaseError> for Diagnostic {
             },
             source: Some("sqruff".to_string()),
             code,
+            fixable: value.fixable,
             // code: todo!(),
             // source: Some(value.get_source().to_string()),
             // code: Some(DiagnosticCode {
@@ -69,6 +77,8 @@ pub struct Diagnostic {
     source: Option<String>,
     // The diagnostic's code, which might appear in the user interface.
     code: Option<String>,
+    /// Whether `sqruff fix` can fix the violation.
+    fixable: bool,
     // An optional property to describe the error code.
     // code_description: Option<CodeDescription>,
     // TODO Maybe implement
@@ -0,0 +1,120 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::core::config::FluffConfig;
+use crate::core::linter::linted_file::LintedFile;
+
+use super::formatters::Formatter;
+
+/// Wraps another formatter and stops reporting violations once a maximum
+/// number has been reported across all files. Every file is still linted, so
+/// the left out violations count towards the exit code.
+///
+/// Files are linted in parallel, so they are held back until the run is
+/// complete and then reported in path order, which keeps the violations that
+/// make the cut the same from one run to the next.
+pub struct MaxViolationsFormatter {
+    inner: Arc<dyn Formatter>,
+    max_violations: usize,
+    files: Mutex<Vec<(LintedFile, bool)>>,
+    has_fail: AtomicBool,
+}
+
+impl MaxViolationsFormatter {
+    pub fn new(inner: Arc<dyn Formatter>, max_violations: usize) -> Self {
+        Self {
+            inner,
+            max_violations,
+            files: Mutex::new(Vec::new()),
+            has_fail: AtomicBool::new(false),
+        }
+    }
+
+    /// Reports the held back files to the wrapped formatter, and returns
+    /// the number of files which had violations left out.
+    ///
+    /// Files past the limit are still reported, without their violations, so
+    /// that the wrapped formatter counts every file which was linted.
+    fn flush(&self) -> usize {
+        let mut files = std::mem::take(&mut *self.files.lock().unwrap());
+        files.sort_by(|(a, _), (b, _)| a.path.cmp(&b.path));
+
+        let mut remaining = self.max_violations;
+        let mut truncated_files = 0;
+        for (mut linted_file, only_fixable) in files {
+            let mut violations = linted_file.get_violations(only_fixable.then_some(true));
+            if violations.len() <= remaining {
+                remaining -= violations.len();
+                self.inner
+                    .dispatch_file_violations(&linted_file, only_fixable);
+                continue;
+            }
+
+            truncated_files += 1;
+            violations.sort_by(|a, b| {
+                a.line_no
+                    .cmp(&b.line_no)
+                    .then_with(|| a.line_pos.cmp(&b.line_pos))
+            });
+            violations.truncate(remaining);
+            remaining = 0;
+
+            linted_file.violations = violations;
+            self.inner.dispatch_file_violations(&linted_file, false);
+        }
+
+        truncated_files
+    }
+}
+
+impl Formatter for MaxViolationsFormatter {
+    fn dispatch_template_header(
+        &self,
+        f_name: String,
+        linter_config: FluffConfig,
+        file_config: FluffConfig,
+    ) {
+        self.inner
+            .dispatch_template_header(f_name, linter_config, file_config);
+    }
+
+    fn dispatch_parse_header(&self, f_name: String) {
+        self.inner.dispatch_parse_header(f_name);
+    }
+
+    fn dispatch_file_violations(&self, linted_file: &LintedFile, only_fixable: bool) {
+        // Left out violations must still be reflected in the exit code.
+        if linted_file
+            .get_violations(only_fixable.then_some(true))
+            .iter()
+            .any(|violation| !violation.ignore && !violation.warning)
+        {
+            self.has_fail.store(true, Ordering::SeqCst);
+        }
+
+        self.files
+            .lock()
+            .unwrap()
+            .push((linted_file.clone(), only_fixable));
+    }
+
+    fn has_fail(&self) -> bool {
+        self.has_fail.load(Ordering::SeqCst) || self.inner.has_fail()
+    }
+
+    fn completion_message(&self) {
+        let truncated_files = self.flush();
+        self.inner.completion_message();
+
+        if truncated_files > 0 {
+            eprintln!(
+                "Output truncated after {} violation(s), leaving out violations in {} file(s).",
+                self.max_violations, truncated_files
+            );
+        }
+    }
+
+    fn needs_fixes(&self) -> bool {
+        self.inner.needs_fixes()
+    }
+}
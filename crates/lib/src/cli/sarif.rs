@@ -0,0 +1,361 @@
+//! Output in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+//! format, as ingested by GitHub code scanning.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use sqruff_lib_core::errors::SQLBaseError;
+use sqruff_lib_core::parser::segments::fix::FixPatch;
+
+use super::formatters::Formatter;
+use crate::core::config::FluffConfig;
+use crate::core::linter::linted_file::LintedFile;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Default)]
+pub struct SarifFormatter {
+    results: Mutex<Vec<SarifResult>>,
+}
+
+impl Formatter for SarifFormatter {
+    fn dispatch_file_violations(&self, linted_file: &LintedFile, only_fixable: bool) {
+        let violations = linted_file.get_violations(only_fixable.then_some(true));
+        let mut results = self.results.lock().unwrap();
+        results.extend(
+            violations
+                .into_iter()
+                .map(|violation| SarifResult::new(linted_file, violation)),
+        );
+    }
+
+    fn has_fail(&self) -> bool {
+        let results = self.results.lock().unwrap();
+        results.iter().any(|result| result.level == Level::Error)
+    }
+
+    fn completion_message(&self) {
+        println!("{}", serde_json::to_string(&self.log()).unwrap());
+    }
+
+    fn dispatch_template_header(
+        &self,
+        _f_name: String,
+        _linter_config: FluffConfig,
+        _file_config: FluffConfig,
+    ) {
+    }
+
+    fn dispatch_parse_header(&self, _f_name: String) {}
+
+    fn needs_fixes(&self) -> bool {
+        true
+    }
+}
+
+impl SarifFormatter {
+    fn log(&self) -> SarifLog {
+        let mut results = std::mem::take(&mut *self.results.lock().unwrap());
+
+        // Only the rules which have been violated are described.
+        let rules: BTreeMap<_, _> = crate::rules::rules()
+            .into_iter()
+            .filter(|rule| {
+                results
+                    .iter()
+                    .any(|result| result.rule_id.as_deref() == Some(rule.code()))
+            })
+            .map(|rule| {
+                let descriptor = ReportingDescriptor {
+                    id: rule.code(),
+                    name: rule.name(),
+                    short_description: Message {
+                        text: rule.description().to_string(),
+                    },
+                };
+                (rule.code(), descriptor)
+            })
+            .collect();
+        for result in &mut results {
+            result.rule_index = result
+                .rule_id
+                .as_deref()
+                .and_then(|rule_id| rules.keys().position(|code| *code == rule_id));
+        }
+
+        SarifLog {
+            schema: SCHEMA,
+            version: "2.1.0",
+            runs: vec![Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "sqruff",
+                        information_uri: env!("CARGO_PKG_REPOSITORY"),
+                        version: env!("CARGO_PKG_VERSION"),
+                        rules: rules.into_values().collect(),
+                    },
+                },
+                results,
+            }],
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+struct Run {
+    tool: Tool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: ToolComponent,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<ReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: &'static str,
+    name: &'static str,
+    short_description: Message,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum Level {
+    Warning,
+    Error,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: Level,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+impl SarifResult {
+    fn new(linted_file: &LintedFile, violation: SQLBaseError) -> Self {
+        let templated_file = &linted_file.templated_file;
+        let (end_line, end_column) = if violation.source_slice.is_empty() {
+            (violation.line_no, violation.line_pos)
+        } else {
+            templated_file.get_line_pos_of_char_pos(violation.source_slice.end, true)
+        };
+
+        let fixes = if violation.fixable {
+            linted_file
+                .patches
+                .iter()
+                .filter(|patch| overlaps(&patch.source_slice, &violation.source_slice))
+                .map(|patch| Fix::new(linted_file, patch))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        SarifResult {
+            rule_id: violation.rule.as_ref().map(|rule| rule.code.to_string()),
+            rule_index: None,
+            level: if violation.warning {
+                Level::Warning
+            } else {
+                Level::Error
+            },
+            message: Message {
+                text: violation.description,
+            },
+            locations: vec![Location {
+                physical_location: PhysicalLocation {
+                    artifact_location: ArtifactLocation::new(&linted_file.path),
+                    region: Region {
+                        start_line: violation.line_no,
+                        start_column: violation.line_pos,
+                        end_line,
+                        end_column,
+                    },
+                },
+            }],
+            fixes,
+        }
+    }
+}
+
+fn overlaps(a: &std::ops::Range<usize>, b: &std::ops::Range<usize>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+impl ArtifactLocation {
+    fn new(path: &str) -> Self {
+        ArtifactLocation {
+            uri: path.replace('\\', "/"),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+impl Fix {
+    fn new(linted_file: &LintedFile, patch: &FixPatch) -> Self {
+        let source = linted_file.templated_file.source_str.as_str();
+        // SARIF counts characters rather than bytes.
+        let char_offset = source[..patch.source_slice.start].chars().count();
+        let char_length = source[patch.source_slice.clone()].chars().count();
+
+        Fix {
+            description: Message {
+                text: format!("Replace with {:?}", patch.fixed_raw.as_str()),
+            },
+            artifact_changes: vec![ArtifactChange {
+                artifact_location: ArtifactLocation::new(&linted_file.path),
+                replacements: vec![Replacement {
+                    deleted_region: CharRegion {
+                        char_offset,
+                        char_length,
+                    },
+                    inserted_content: Message {
+                        text: patch.fixed_raw.to_string(),
+                    },
+                }],
+            }],
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: CharRegion,
+    inserted_content: Message,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CharRegion {
+    char_offset: usize,
+    char_length: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::core::linter::core::Linter;
+
+    #[test]
+    fn test_sarif_lint() {
+        let formatter = Arc::new(SarifFormatter::default());
+        let config = FluffConfig::from_source("[sqruff]\nrules = LT01\n", None);
+        let linter = Linter::new(
+            config,
+            Some(formatter.clone() as Arc<dyn Formatter>),
+            None,
+            false,
+        );
+
+        linter.lint_string("SELECT 1 ,4\n", Some("query.sql".into()), false);
+        let log = serde_json::to_value(formatter.log()).unwrap();
+
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(log["runs"][0]["tool"]["driver"]["rules"][0]["id"], "LT01");
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "LT01");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "query.sql"
+        );
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"],
+            1
+        );
+
+        // The fixes are computed without the file being fixed.
+        let replacements: Vec<_> = log["runs"][0]["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|result| result["fixes"].as_array().cloned().unwrap_or_default())
+            .map(|fix| fix["artifactChanges"][0]["replacements"][0]["deletedRegion"].clone())
+            .collect();
+        assert!(!replacements.is_empty());
+    }
+
+    #[test]
+    fn test_sarif_overlaps() {
+        assert!(overlaps(&(2..5), &(4..6)));
+        assert!(overlaps(&(2..5), &(3..4)));
+        assert!(!overlaps(&(2..5), &(5..6)));
+        assert!(!overlaps(&(5..6), &(2..5)));
+    }
+}
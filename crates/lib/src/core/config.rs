@@ -7,9 +7,10 @@ use configparser::ini::Ini;
 use itertools::Itertools;
 use sqruff_lib_core::dialects::base::Dialect;
 use sqruff_lib_core::dialects::init::{dialect_readout, DialectKind};
+use sqruff_lib_core::dialects::registry::DialectRegistry;
 use sqruff_lib_core::errors::SQLFluffUserError;
 use sqruff_lib_core::parser::parser::Parser;
-use sqruff_lib_dialects::kind_to_dialect;
+use sqruff_lib_dialects::{kind_to_dialect, kind_to_dialect_with_batch_separator};
 
 use crate::utils::reflow::config::ReflowConfig;
 
@@ -68,6 +69,17 @@ impl FluffConfig {
         FluffConfig::new(configs, None, None)
     }
 
+    /// from_json creates a config object from a JSON object, without touching the file system.
+    ///
+    /// Each section of the ini format is a nested object, with the `[sqruff]` section under
+    /// `core`, e.g. `{"core": {"dialect": "postgres"}, "rules": {"capitalisation.keywords":
+    /// {"capitalisation_policy": "upper"}}}`.
+    pub fn from_json(json: &str) -> Result<FluffConfig, SQLFluffUserError> {
+        let configs = serde_json::from_str(json)
+            .map_err(|err| SQLFluffUserError::new(format!("Invalid JSON config: {err}")))?;
+        Ok(FluffConfig::new(configs, None, None))
+    }
+
     pub fn get_section(&self, section: &str) -> &AHashMap<String, Value> {
         self.raw[section].as_map().unwrap()
     }
@@ -110,12 +122,21 @@ impl FluffConfig {
             .get("core")
             .and_then(|map| map.as_map().unwrap().get("dialect"))
         {
-            None => DialectKind::default(),
-            Some(Value::String(std)) => DialectKind::from_str(std).unwrap(),
-            _value => DialectKind::default(),
+            None => kind_to_dialect(&DialectKind::default()),
+            Some(Value::String(std)) => match DialectKind::from_str(std) {
+                Ok(kind) => match configs["core"]["batch_separator"].as_string() {
+                    Some(batch_separator) => {
+                        kind_to_dialect_with_batch_separator(&kind, batch_separator)
+                    }
+                    None => kind_to_dialect(&kind),
+                },
+                Err(error) => {
+                    Some(DialectRegistry::get(std).unwrap_or_else(|| panic!("{std}: {error}")))
+                }
+            },
+            _value => kind_to_dialect(&DialectKind::default()),
         };
 
-        let dialect = kind_to_dialect(&dialect);
         for (in_key, out_key) in [
             // Deal with potential ignore & warning parameters
             ("ignore", "ignore"),
@@ -161,11 +182,45 @@ impl FluffConfig {
         this
     }
 
+    /// Restricts the rules to run to `rules`, rule codes, names or groups. Only the rules selected
+    /// by both `rules` and this restriction, and not excluded by `exclude_rules`, are run.
+    pub fn restrict_rules(&mut self, rules: &[&str]) {
+        let rules = rules
+            .iter()
+            .map(|rule| Value::String((*rule).into()))
+            .collect();
+
+        self.raw
+            .get_mut("core")
+            .unwrap()
+            .as_map_mut()
+            .unwrap()
+            .insert("rule_restriction".into(), Value::Array(rules));
+    }
+
     pub fn with_sql_file_exts(mut self, exts: Vec<String>) -> Self {
         self.sql_file_exts = exts;
         self
     }
 
+    /// Loads the config which applies to the file at `path`, from the config files in its
+    /// directory and the directories above it. The file itself doesn't need to exist.
+    pub fn from_path(path: &Path) -> Result<FluffConfig, SQLFluffUserError> {
+        let dir = std::path::absolute(path)
+            .ok()
+            .and_then(|path| path.parent().map(Path::to_path_buf))
+            .filter(|dir| dir.is_dir())
+            .ok_or_else(|| {
+                SQLFluffUserError::new(format!(
+                    "The directory of '{}' does not exist.",
+                    path.display()
+                ))
+            })?;
+
+        let configs = ConfigLoader {}.load_config_up_to_path(dir, None, false);
+        Ok(FluffConfig::new(configs, None, None))
+    }
+
     /// Loads a config object just based on the root directory.
     // TODO This is not a translation that is particularly accurate.
     pub fn from_root(
@@ -470,7 +525,7 @@ impl ConfigLoader {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
 pub enum Value {
     Int(i32),
@@ -504,6 +559,28 @@ impl Value {
     }
 }
 
+/// Formats the value the way it is written in a config file.
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(v) => write!(f, "{v}"),
+            Value::Bool(true) => f.write_str("True"),
+            Value::Bool(false) => f.write_str("False"),
+            Value::Float(v) => write!(f, "{v}"),
+            Value::String(v) => f.write_str(v),
+            Value::Map(map) => {
+                let entries = map
+                    .iter()
+                    .sorted_by_key(|(key, _)| key.as_str())
+                    .map(|(key, value)| format!("{key}={value}"));
+                write!(f, "{}", entries.format(", "))
+            }
+            Value::Array(values) => write!(f, "{}", values.iter().format(",")),
+            Value::None => f.write_str("None"),
+        }
+    }
+}
+
 impl Index<&str> for Value {
     type Output = Value;
 
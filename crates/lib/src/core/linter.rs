@@ -1,3 +1,4 @@
+pub mod cache;
 pub mod common;
 pub mod core;
 pub mod linted_dir;
@@ -0,0 +1,131 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use ahash::AHashSet;
+use itertools::Itertools;
+use sha2::{Digest, Sha256};
+
+use crate::core::config::{FluffConfig, Value};
+use crate::core::rules::base::ErasedRule;
+
+/// The name of the file, in the cache directory, listing the files which passed.
+const CACHE_FILE_NAME: &str = "lint";
+
+/// A persistent record of the files which were linted without any violation, so that they can
+/// be skipped as long as neither they nor the config change.
+///
+/// An entry is a SHA-256 of the path and content of a file, and of everything else that affects
+/// its linting: the config, the rules, and the version of sqruff. Unlike the std hashers, its
+/// output doesn't change between Rust releases, so the cache stays valid across toolchains.
+pub struct LintCache {
+    dir: PathBuf,
+    fingerprint: Sha256,
+    passed: AHashSet<String>,
+    new_passed: Mutex<Vec<String>>,
+}
+
+impl LintCache {
+    /// Loads the cache in `dir`, which is created when the cache is saved if it doesn't exist.
+    pub fn load(
+        dir: impl Into<PathBuf>,
+        config: &FluffConfig,
+        rules: &[ErasedRule],
+        include_parse_errors: bool,
+    ) -> Self {
+        let dir = dir.into();
+
+        let mut fingerprint = Sha256::new();
+        update(&mut fingerprint, env!("CARGO_PKG_VERSION"));
+        // Maps are formatted with sorted keys, so the formatted config is stable.
+        update(
+            &mut fingerprint,
+            &Value::Map(config.raw.clone()).to_string(),
+        );
+        for code in rules.iter().map(|rule| rule.code()).sorted() {
+            update(&mut fingerprint, code);
+        }
+        fingerprint.update([include_parse_errors as u8]);
+
+        let passed = std::fs::read_to_string(dir.join(CACHE_FILE_NAME))
+            .map(|contents| contents.lines().map(ToOwned::to_owned).collect())
+            .unwrap_or_default();
+
+        LintCache {
+            dir,
+            fingerprint,
+            passed,
+            new_passed: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn key(&self, path: &str, source: &str) -> String {
+        let mut hasher = self.fingerprint.clone();
+        update(&mut hasher, path);
+        update(&mut hasher, source);
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Whether the file passed the last time it was linted with the same config.
+    pub fn has_passed(&self, path: &str, source: &str) -> bool {
+        self.passed.contains(&self.key(path, source))
+    }
+
+    /// Records that the file was linted without any violation.
+    pub fn mark_passed(&self, path: &str, source: &str) {
+        let key = self.key(path, source);
+        self.new_passed.lock().unwrap().push(key);
+    }
+
+    /// Writes the cache back to its directory, together with a `.gitignore` so that it doesn't
+    /// get committed.
+    pub fn save(&self) -> std::io::Result<()> {
+        let new_passed = self.new_passed.lock().unwrap();
+        if new_passed.is_empty() {
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.dir.join(".gitignore"), "*\n")?;
+
+        let contents = self
+            .passed
+            .iter()
+            .chain(new_passed.iter())
+            .unique()
+            .map(|key| format!("{key}\n"))
+            .collect::<String>();
+        std::fs::write(self.dir.join(CACHE_FILE_NAME), contents)
+    }
+}
+
+/// Hashes `value` prefixed with its length, so that consecutive values can't run into each
+/// other.
+fn update(hasher: &mut Sha256, value: &str) {
+    hasher.update((value.len() as u64).to_le_bytes());
+    hasher.update(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint_cache_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = FluffConfig::new(<_>::default(), None, None);
+
+        let cache = LintCache::load(dir.path(), &config, &[], false);
+        assert!(!cache.has_passed("query.sql", "SELECT 1\n"));
+        cache.mark_passed("query.sql", "SELECT 1\n");
+        cache.save().unwrap();
+
+        let cache = LintCache::load(dir.path(), &config, &[], false);
+        assert!(cache.has_passed("query.sql", "SELECT 1\n"));
+        assert!(!cache.has_passed("query.sql", "SELECT 2\n"));
+
+        // Any change to the config invalidates the cache.
+        let config = FluffConfig::from_source("[sqruff]\ndialect = postgres\n", None);
+        let cache = LintCache::load(dir.path(), &config, &[], false);
+        assert!(!cache.has_passed("query.sql", "SELECT 1\n"));
+    }
+}
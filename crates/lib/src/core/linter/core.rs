@@ -1,9 +1,8 @@
 use std::borrow::Cow;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
 
+use super::cache::LintCache;
 use super::linted_dir::LintedDir;
 use crate::cli::formatters::Formatter;
 use crate::core::config::FluffConfig;
@@ -16,6 +15,7 @@ use crate::rules::get_ruleset;
 use crate::templaters::raw::RawTemplater;
 use crate::templaters::{Templater, TEMPLATERS};
 use ahash::{AHashMap, AHashSet};
+use ignore::WalkBuilder;
 use itertools::Itertools;
 use rayon::iter::{IntoParallelRefIterator as _, ParallelIterator as _};
 use smol_str::{SmolStr, ToSmolStr};
@@ -32,13 +32,16 @@ use sqruff_lib_core::parser::parser::Parser;
 use sqruff_lib_core::parser::segments::base::{ErasedSegment, Tables};
 use sqruff_lib_core::parser::segments::fix::SourceFix;
 use sqruff_lib_core::templaters::base::TemplatedFile;
-use walkdir::WalkDir;
+
+/// The name of the files listing paths for sqruff to ignore, with the syntax of `.gitignore`.
+const IGNORE_FILE_NAME: &str = ".sqruffignore";
 
 pub struct Linter {
     config: FluffConfig,
     formatter: Option<Arc<dyn Formatter>>,
     templater: &'static dyn Templater,
     rules: OnceLock<Vec<ErasedRule>>,
+    cache: Option<LintCache>,
 
     /// include_parse_errors is a flag to indicate whether to include parse errors in the output
     include_parse_errors: bool,
@@ -60,10 +63,25 @@ impl Linter {
             formatter,
             templater,
             rules: OnceLock::new(),
+            cache: None,
             include_parse_errors,
         }
     }
 
+    /// Skips the files which passed the last time they were linted, as recorded in the cache
+    /// in `dir`. This only applies to [`Linter::lint_paths`] when not fixing.
+    ///
+    /// Only the raw templater is cached, as the output of the others also depends on inputs
+    /// which the cache can't see, such as environment variables or macro files.
+    pub fn enable_cache(&mut self, dir: impl Into<PathBuf>) {
+        if self.templater.name() != RawTemplater.name() {
+            return;
+        }
+
+        let cache = LintCache::load(dir, &self.config, self.rules(), self.include_parse_errors);
+        self.cache = Some(cache);
+    }
+
     pub fn get_templater(config: &FluffConfig) -> &'static dyn Templater {
         let templater_name = config.get("templater", "core").as_string();
         match templater_name {
@@ -168,18 +186,53 @@ impl Linter {
         expanded_paths
             .par_iter()
             .filter(|path| !ignorer(Path::new(path)))
-            .map(|path| {
-                let rendered = self.render_file(path.clone());
-                self.lint_rendered(rendered, fix)
-            })
+            .map(|path| self.lint_path(path, fix))
             .for_each(|linted_file| {
                 let path = expanded_path_to_linted_dir[&linted_file.path];
                 result.paths[path].add(linted_file);
             });
 
+        if let Some(cache) = &self.cache {
+            if let Err(err) = cache.save() {
+                tracing::warn!("Failed to save the lint cache: {err}");
+            }
+        }
+
         result
     }
 
+    fn lint_path(&self, path: &str, fix: bool) -> LintedFile {
+        let Some(cache) = self.cache.as_ref().filter(|_| !fix) else {
+            let rendered = self.render_file(path.to_string());
+            return self.lint_rendered(rendered, fix);
+        };
+
+        let source = std::fs::read_to_string(path).unwrap();
+        let rendered = self
+            .render_string(&source, path.to_string(), &self.config)
+            .unwrap();
+
+        if cache.has_passed(path, &source) {
+            // Only raw files are cached, so rendering is cheap, and gives the formatters the
+            // same record of the file as linting it would have.
+            let linted_file = LintedFile {
+                path: path.to_string(),
+                templated_file: rendered.templated_file,
+                ..LintedFile::default()
+            };
+            if let Some(formatter) = &self.formatter {
+                formatter.dispatch_file_violations(&linted_file, false);
+            }
+            return linted_file;
+        }
+
+        let linted_file = self.lint_rendered(rendered, fix);
+        if linted_file.violations.is_empty() {
+            cache.mark_passed(path, &source);
+        }
+        linted_file
+    }
+
     pub fn get_rulepack(&self) -> RulePack {
         let rs = get_ruleset();
         rs.get_rulepack(&self.config)
@@ -203,6 +256,12 @@ impl Linter {
         fix: bool,
     ) -> LintedFile {
         let mut violations = parsed_string.violations;
+        // Computing the fixes doesn't write them, it only makes them available to the formatter.
+        let fix = fix
+            || self
+                .formatter
+                .as_ref()
+                .is_some_and(|formatter| formatter.needs_fixes());
 
         let (patches, ignore_mask, initial_linting_errors) =
             parsed_string
@@ -567,105 +626,45 @@ impl Linter {
             }
         };
 
-        // Files referred to exactly are also ignored if
-        // matched, but we warn the users when that happens
-        let is_exact_file = metadata.is_file();
-
-        let mut path_walk = if is_exact_file {
-            let path = Path::new(&path);
-            let dirpath = path.parent().unwrap().to_str().unwrap().to_string();
-            let files = vec![path.file_name().unwrap().to_str().unwrap().to_string()];
-            vec![(dirpath, None, files)]
+        let paths = if metadata.is_file() {
+            vec![path]
         } else {
-            WalkDir::new(&path)
-                .into_iter()
-                .filter_map(Result::ok) // Filter out the Result and get DirEntry
-                .map(|entry| {
-                    let dirpath = entry.path().parent().unwrap().to_str().unwrap().to_string();
-                    let files = vec![entry.file_name().to_str().unwrap().to_string()];
-                    (dirpath, None, files)
+            // Ignore files apply to the directories they are in, like `.gitignore`, which is
+            // honoured as well.
+            let mut walker = WalkBuilder::new(&path);
+            if ignore_files {
+                walker
+                    .hidden(false)
+                    .filter_entry(|entry| entry.file_name() != ".git")
+                    .add_custom_ignore_filename(IGNORE_FILE_NAME)
+                    .add_custom_ignore_filename(&ignore_file_name);
+            } else {
+                walker.standard_filters(false);
+            }
+
+            walker
+                .build()
+                .filter_map(Result::ok)
+                .filter(|entry| {
+                    entry
+                        .file_type()
+                        .is_some_and(|file_type| file_type.is_file())
                 })
+                .map(|entry| entry.into_path())
                 .collect_vec()
         };
 
-        // TODO:
-        // let ignore_file_paths = ConfigLoader.find_ignore_config_files(
-        //     path=path, working_path=working_path, ignore_file_name=ignore_file_name
-        // );
-        let ignore_file_paths: Vec<String> = Vec::new();
-
-        // Add paths that could contain "ignore files"
-        // to the path_walk list
-        let path_walk_ignore_file: Vec<(String, Option<()>, Vec<String>)> = ignore_file_paths
-            .iter()
-            .map(|ignore_file_path| {
-                let ignore_file_path = Path::new(ignore_file_path);
-
-                // Extracting the directory name from the ignore file path
-                let dir_name = ignore_file_path
-                    .parent()
-                    .unwrap()
-                    .to_str()
-                    .unwrap()
-                    .to_string();
-
-                // Only one possible file, since we only
-                // have one "ignore file name"
-                let file_name = vec![ignore_file_path
-                    .file_name()
-                    .unwrap()
-                    .to_str()
-                    .unwrap()
-                    .to_string()];
-
-                (dir_name, None, file_name)
-            })
-            .collect();
-
-        path_walk.extend(path_walk_ignore_file);
-
-        let mut buffer = Vec::new();
-        let mut ignores = AHashMap::new();
         let sql_file_exts = self.config.sql_file_exts();
-
-        for (dirpath, _, filenames) in path_walk {
-            for fname in filenames {
-                let fpath = Path::new(&dirpath).join(&fname);
-
-                // Handle potential .sqlfluffignore files
-                if ignore_files && fname == ignore_file_name {
-                    let file = File::open(&fpath).unwrap();
-                    let lines = BufReader::new(file).lines();
-                    let spec = lines.map_while(Result::ok); // Simple placeholder for pathspec logic
-                    ignores.insert(dirpath.clone(), spec.collect::<Vec<String>>());
-
-                    // We don't need to process the ignore file any further
-                    continue;
-                }
-
-                // We won't purge files *here* because there's an edge case
-                // that the ignore file is processed after the sql file.
-
-                // Scan for remaining files
-                for ext in sql_file_exts {
-                    // is it a sql file?
-                    if fname.to_lowercase().ends_with(ext) {
-                        buffer.push(fpath.clone());
-                    }
-                }
-            }
-        }
-
-        let mut filtered_buffer = AHashSet::new();
-
-        for fpath in buffer {
-            let npath = helpers::normalize(&fpath).to_str().unwrap().to_string();
-            filtered_buffer.insert(npath);
-        }
-
-        let mut files = filtered_buffer.into_iter().collect_vec();
-        files.sort();
-        files
+        paths
+            .into_iter()
+            .filter(|fpath| {
+                let fname = fpath.file_name().unwrap().to_string_lossy().to_lowercase();
+                sql_file_exts.iter().any(|ext| fname.ends_with(ext))
+            })
+            .map(|fpath| helpers::normalize(&fpath).to_str().unwrap().to_string())
+            .sorted()
+            .dedup()
+            .collect()
     }
 
     pub fn config(&self) -> &FluffConfig {
@@ -704,6 +703,30 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn test_linter_cache_skips_templated_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("query.sql");
+        std::fs::write(&path, "SELECT $SQRUFF_TEST_CACHE_COLUMNS FROM orders\n").unwrap();
+        let config = FluffConfig::from_source("[sqruff]\ntemplater = env\n", None);
+
+        let lint = |columns: &str| {
+            std::env::set_var("SQRUFF_TEST_CACHE_COLUMNS", columns);
+            let mut linter = Linter::new(config.clone(), None, None, false);
+            linter.enable_cache(dir.path().join(".sqruff_cache"));
+            let result = linter.lint_paths(vec![path.clone()], false, &|_| false);
+            result.paths[0]
+                .files
+                .iter()
+                .map(|file| file.violations.len())
+                .sum::<usize>()
+        };
+
+        assert_eq!(lint("id"), 0);
+        // The file is unchanged, but what it renders to isn't.
+        assert_ne!(lint("id,name"), 0);
+    }
+
     #[test]
     fn test_linter_path_from_paths_dir() {
         // Test extracting paths from directories.
@@ -786,6 +809,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_linter_path_from_paths_sqruffignore() {
+        let lntr = Linter::new(
+            FluffConfig::new(<_>::default(), None, None),
+            None,
+            None,
+            false,
+        );
+        let paths = lntr.paths_from_path(
+            "test/fixtures/linter/sqruffignore".into(),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(
+            normalise_paths(paths),
+            &["test.fixtures.linter.sqruffignore.kept.sql"]
+        );
+    }
+
     // test__linter__skip_large_bytes
     // test__linter__path_from_paths__not_exist
     // test__linter__path_from_paths__not_exist_ignore
@@ -847,6 +892,23 @@ mod tests {
         let _parsed = linter.parse_string(&tables, &sql, None).unwrap();
     }
 
+    #[test]
+    fn test_fix_round_trips_alter_table() {
+        let sql = "ALTER TABLE x RENAME TO y;\nALTER TABLE y RENAME COLUMN a TO b;\nALTER TABLE \
+                   y RENAME CONSTRAINT y_pkey TO y_primary_key;\nALTER TABLE y DROP COLUMN b;\n";
+
+        let linter = Linter::new(
+            FluffConfig::new(<_>::default(), None, None),
+            None,
+            None,
+            false,
+        );
+        let linted = linter.lint_string(sql, None, true);
+
+        assert!(linted.violations.is_empty());
+        assert_eq!(linted.fix_string(), sql);
+    }
+
     #[test]
     fn test_normalise_newlines() {
         let in_str = "SELECT\r\n foo\n FROM \r \n\r bar;";
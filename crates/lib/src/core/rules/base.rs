@@ -181,6 +181,7 @@ pub trait Rule: CloneRule + dyn_clone::DynClone + Debug + 'static + Send + Sync
         config: &FluffConfig,
     ) -> Vec<SQLLintError> {
         let mut root_context = RuleContext::new(tables, dialect, config, tree.clone());
+        root_context.path = Some(templated_file.name().to_string());
         let mut vs = Vec::new();
 
         // TODO Will to return a note that rules were skipped
@@ -397,12 +398,29 @@ impl RuleSet {
             None => Vec::new(),
         };
 
+        let restriction = config
+            .get("rule_restriction", "core")
+            .as_array()
+            .map(|array| {
+                array
+                    .iter()
+                    .map(|it| it.as_string().unwrap().to_owned())
+                    .collect::<Vec<_>>()
+            });
+
         let expanded_allowlist = self.expand_rule_refs(allowlist, &reference_map);
         let expanded_denylist = self.expand_rule_refs(denylist, &reference_map);
+        let expanded_restriction =
+            restriction.map(|restriction| self.expand_rule_refs(restriction, &reference_map));
 
         let keylist: Vec<_> = keylist
             .into_iter()
             .filter(|&&r| expanded_allowlist.contains(r) && !expanded_denylist.contains(r))
+            .filter(|&&r| {
+                expanded_restriction
+                    .as_ref()
+                    .is_none_or(|restriction| restriction.contains(r))
+            })
             .collect();
 
         for code in keylist {
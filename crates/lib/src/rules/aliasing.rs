@@ -9,6 +9,8 @@ pub mod al06;
 pub mod al07;
 pub mod al08;
 pub mod al09;
+pub mod al10;
+pub mod al11;
 
 pub fn rules() -> Vec<ErasedRule> {
     use crate::core::rules::base::Erased as _;
@@ -23,5 +25,7 @@ pub fn rules() -> Vec<ErasedRule> {
         al07::RuleAL07::default().erased(),
         al08::RuleAL08.erased(),
         al09::RuleAL09.erased(),
+        al10::RuleAL10::default().erased(),
+        al11::RuleAL11.erased(),
     ]
 }
@@ -165,7 +165,7 @@ FROM foo
     }
 }
 
-fn recursively_check_is_complex(select_clause_or_exp_children: Segments) -> bool {
+pub(crate) fn recursively_check_is_complex(select_clause_or_exp_children: Segments) -> bool {
     let selector: Option<fn(&ErasedSegment) -> bool> = Some(|it: &ErasedSegment| {
         !matches!(
             it.get_type(),
@@ -8,6 +8,7 @@ use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups}
 use crate::core::rules::context::RuleContext;
 use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
 use crate::utils::functional::context::FunctionalContext;
+use crate::utils::identifers::identifiers_match;
 
 #[derive(Default, Clone, Debug)]
 pub struct RuleAL09;
@@ -121,10 +122,11 @@ FROM table;
                             })
                             .expect("identifier is none");
 
-                        if column_identifier
-                            .raw()
-                            .eq_ignore_ascii_case(alias_identifier.raw())
-                        {
+                        if identifiers_match(
+                            context.dialect.name,
+                            &column_identifier,
+                            &alias_identifier,
+                        ) {
                             let fixes = vec![
                                 LintFix::delete(whitespace),
                                 LintFix::delete(alias_expression),
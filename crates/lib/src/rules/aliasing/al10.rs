@@ -0,0 +1,220 @@
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::{ErasedSegment, SegmentBuilder};
+use sqruff_lib_core::utils::functional::segments::Segments;
+
+use super::al03::recursively_check_is_complex;
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleAL10 {
+    force_enable: bool,
+    require_all: bool,
+    generate_aliases: bool,
+}
+
+impl Rule for RuleAL10 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleAL10 {
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+            require_all: config["require_all"].as_bool().unwrap_or_default(),
+            generate_aliases: config["generate_aliases"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "aliasing.consistent_expressions"
+    }
+
+    fn description(&self) -> &'static str {
+        "Computed columns in a select clause should be aliased consistently."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled by default and can be enabled with the `force_enable`
+config, as it conflicts with AL03 when that requires every column expression
+to be aliased.
+
+Plain column references are not considered. When only some computed columns
+are aliased, the minority style is flagged. Set `require_all` to instead
+require an alias on every computed column, and `generate_aliases` to add
+aliases such as `col_2` to unaliased columns when fixing.
+
+**Anti-pattern**
+
+In this example, only one of the sums is aliased.
+
+```sql
+SELECT
+    id,
+    sum(a) AS a_sum,
+    sum(b)
+FROM foo
+GROUP BY id
+```
+
+**Best practice**
+
+Alias either all or none of the computed columns.
+
+```sql
+SELECT
+    id,
+    sum(a) AS a_sum,
+    sum(b) AS b_sum
+FROM foo
+GROUP BY id
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Aliasing]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let elements: Vec<&ErasedSegment> = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_type(SyntaxKind::SelectClauseElement))
+            .collect();
+
+        let mut existing_aliases = AHashSet::new();
+        let mut aliased = Vec::new();
+        let mut unaliased = Vec::new();
+
+        for (position, &element) in elements.iter().enumerate() {
+            let children = Segments::new(element.clone(), None).children(None);
+
+            if let Some(alias) = children
+                .iter()
+                .find(|it| it.is_type(SyntaxKind::AliasExpression))
+            {
+                if let Some(identifier) = alias.segments().iter().rev().find(|it| it.is_code()) {
+                    existing_aliases.insert(identifier.raw().to_lowercase());
+                }
+            }
+
+            let children = children.select(
+                Some(|it: &ErasedSegment| {
+                    !it.is_type(SyntaxKind::Star) && !it.is_type(SyntaxKind::AliasExpression)
+                }),
+                None,
+                None,
+                None,
+            );
+            if !recursively_check_is_complex(children) {
+                continue;
+            }
+
+            if element
+                .child(const { &SyntaxSet::new(&[SyntaxKind::AliasExpression]) })
+                .is_some()
+            {
+                aliased.push((position, element));
+            } else {
+                unaliased.push((position, element));
+            }
+        }
+
+        if unaliased.is_empty() || (aliased.is_empty() && !self.require_all) {
+            return Vec::new();
+        }
+
+        // Ties and `require_all` flag the unaliased columns, which are the only ones
+        // that can be fixed.
+        if !self.require_all && unaliased.len() > aliased.len() {
+            return aliased
+                .into_iter()
+                .map(|(_, element)| {
+                    LintResult::new(
+                        Some(element.clone()),
+                        Vec::new(),
+                        Some(
+                            "Column is aliased while most computed columns in this select \
+                             clause are not."
+                                .into(),
+                        ),
+                        None,
+                    )
+                })
+                .collect();
+        }
+
+        unaliased
+            .into_iter()
+            .map(|(position, element)| {
+                let fixes = if self.generate_aliases {
+                    self.generate_alias(context, position, element, &existing_aliases)
+                } else {
+                    Vec::new()
+                };
+
+                LintResult::new(
+                    Some(element.clone()),
+                    fixes,
+                    Some("Computed column should be aliased consistently.".into()),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectClause]) }).into()
+    }
+}
+
+impl RuleAL10 {
+    fn generate_alias(
+        &self,
+        context: &RuleContext,
+        position: usize,
+        element: &ErasedSegment,
+        existing_aliases: &AHashSet<String>,
+    ) -> Vec<LintFix> {
+        let name = format!("col_{}", position + 1);
+        if existing_aliases.contains(&name) {
+            return Vec::new();
+        }
+
+        let Some(anchor) = element.segments().iter().rev().find(|it| it.is_code()) else {
+            return Vec::new();
+        };
+
+        let tables = context.tables;
+        let alias = SegmentBuilder::node(
+            tables.next_id(),
+            SyntaxKind::AliasExpression,
+            context.dialect.name,
+            vec![
+                SegmentBuilder::keyword(tables.next_id(), "AS"),
+                SegmentBuilder::whitespace(tables.next_id(), " "),
+                SegmentBuilder::token(tables.next_id(), &name, SyntaxKind::NakedIdentifier)
+                    .finish(),
+            ],
+        )
+        .finish();
+
+        vec![LintFix::create_after(
+            anchor.clone(),
+            vec![SegmentBuilder::whitespace(tables.next_id(), " "), alias],
+            None,
+        )]
+    }
+}
@@ -0,0 +1,143 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::identifers::identifiers_match;
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleAL11;
+
+impl Rule for RuleAL11 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleAL11.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "aliasing.self_alias.table"
+    }
+
+    fn description(&self) -> &'static str {
+        "Find self-aliased tables and fix them"
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Aliasing the table to its own name.
+
+```sql
+SELECT
+    orders.id
+FROM orders AS orders;
+```
+
+**Best practice**
+
+Drop the alias, the table can already be referred to by its name.
+
+```sql
+SELECT
+    orders.id
+FROM orders;
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Aliasing]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let element = &context.segment;
+
+        let Some(alias_expression) =
+            element.child(const { &SyntaxSet::new(&[SyntaxKind::AliasExpression]) })
+        else {
+            return Vec::new();
+        };
+
+        // An alias with a column list renames the columns too.
+        if alias_expression
+            .child(const { &SyntaxSet::new(&[SyntaxKind::Bracketed]) })
+            .is_some()
+        {
+            return Vec::new();
+        }
+
+        let Some(table_reference) = element
+            .child(const { &SyntaxSet::new(&[SyntaxKind::TableExpression]) })
+            .and_then(|it| it.child(const { &SyntaxSet::new(&[SyntaxKind::TableReference]) }))
+        else {
+            return Vec::new();
+        };
+
+        let Some(table_identifier) = table_reference
+            .segments()
+            .iter()
+            .rev()
+            .find(|it| {
+                matches!(
+                    it.get_type(),
+                    SyntaxKind::NakedIdentifier | SyntaxKind::QuotedIdentifier
+                )
+            })
+            .cloned()
+        else {
+            return Vec::new();
+        };
+
+        let Some(alias_identifier) = alias_expression.child(
+            const { &SyntaxSet::new(&[SyntaxKind::NakedIdentifier, SyntaxKind::QuotedIdentifier]) },
+        ) else {
+            return Vec::new();
+        };
+
+        if !identifiers_match(context.dialect.name, &table_identifier, &alias_identifier) {
+            return Vec::new();
+        }
+
+        // Remove the alias together with the whitespace separating it from the
+        // table, unless a comment sits in between.
+        let children = element.segments();
+        let alias_idx = children
+            .iter()
+            .position(|it| it == &alias_expression)
+            .unwrap();
+        let gap_start = children[..alias_idx]
+            .iter()
+            .rposition(|it| it.is_code())
+            .map_or(alias_idx, |idx| idx + 1);
+        let gap = &children[gap_start..alias_idx];
+
+        let fixes = if gap.iter().any(|it| it.is_comment()) {
+            Vec::new()
+        } else {
+            gap.iter()
+                .cloned()
+                .chain(Some(alias_expression.clone()))
+                .map(LintFix::delete)
+                .collect()
+        };
+
+        vec![LintResult::new(
+            Some(alias_expression),
+            fixes,
+            Some("Table should not be self-aliased.".into()),
+            None,
+        )]
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::FromExpressionElement]) })
+            .into()
+    }
+}
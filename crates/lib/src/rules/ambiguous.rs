@@ -7,6 +7,14 @@ pub mod am04;
 pub mod am05;
 pub mod am06;
 pub mod am07;
+pub mod am08;
+pub mod am09;
+pub mod am10;
+pub mod am11;
+pub mod am12;
+pub mod am13;
+pub mod am14;
+pub mod am15;
 
 pub fn rules() -> Vec<ErasedRule> {
     use crate::core::rules::base::Erased as _;
@@ -19,5 +27,13 @@ pub fn rules() -> Vec<ErasedRule> {
         am05::RuleAM05::default().erased(),
         am06::RuleAM06::default().erased(),
         am07::RuleAM07.erased(),
+        am08::RuleAM08.erased(),
+        am09::RuleAM09.erased(),
+        am10::RuleAM10::default().erased(),
+        am11::RuleAM11.erased(),
+        am12::RuleAM12::default().erased(),
+        am13::RuleAM13.erased(),
+        am14::RuleAM14.erased(),
+        am15::RuleAM15::default().erased(),
     ]
 }
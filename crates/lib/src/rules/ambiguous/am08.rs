@@ -0,0 +1,127 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone)]
+pub struct RuleAM08;
+
+impl Rule for RuleAM08 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleAM08.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.in_list_types"
+    }
+
+    fn description(&self) -> &'static str {
+        "IN lists should not mix numeric and string literals."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Mixing literal types in an `IN` list relies on implicit conversions, and is
+usually a mistake.
+
+```sql
+SELECT *
+FROM orders
+WHERE status IN (1, 'active')
+```
+
+**Best practice**
+
+Use literals of a single type.
+
+```sql
+SELECT *
+FROM orders
+WHERE status IN ('pending', 'active')
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let code: Vec<&ErasedSegment> = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .collect();
+
+        let mut results = Vec::new();
+        for (idx, segment) in code.iter().enumerate() {
+            if !segment.is_keyword("IN") {
+                continue;
+            }
+
+            let Some(&list) = code
+                .get(idx + 1)
+                .filter(|it| it.is_type(SyntaxKind::Bracketed))
+            else {
+                continue;
+            };
+
+            let mut has_numeric = false;
+            let mut has_string = false;
+            let mut inferable = true;
+
+            for element in list.segments().iter().filter(|it| {
+                it.is_code()
+                    && !matches!(
+                        it.get_type(),
+                        SyntaxKind::StartBracket | SyntaxKind::EndBracket | SyntaxKind::Comma
+                    )
+            }) {
+                match unwrap_expression(element).get_type() {
+                    SyntaxKind::NumericLiteral => has_numeric = true,
+                    SyntaxKind::QuotedLiteral => has_string = true,
+                    SyntaxKind::NullLiteral => {}
+                    _ => {
+                        inferable = false;
+                        break;
+                    }
+                }
+            }
+
+            if inferable && has_numeric && has_string {
+                results.push(LintResult::new(
+                    Some(list.clone()),
+                    Vec::new(),
+                    Some("IN list mixes numeric and string literals.".into()),
+                    None,
+                ));
+            }
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Expression]) }).into()
+    }
+}
+
+/// Some dialects wrap each list element in an expression.
+fn unwrap_expression(segment: &ErasedSegment) -> &ErasedSegment {
+    if !segment.is_type(SyntaxKind::Expression) {
+        return segment;
+    }
+
+    let mut code = segment.segments().iter().filter(|it| it.is_code());
+    match (code.next(), code.next()) {
+        (Some(inner), None) => unwrap_expression(inner),
+        _ => segment,
+    }
+}
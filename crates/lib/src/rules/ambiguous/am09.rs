@@ -0,0 +1,136 @@
+use ahash::AHashMap;
+use itertools::Itertools;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone)]
+pub struct RuleAM09;
+
+impl Rule for RuleAM09 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleAM09.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.merge_clause_order"
+    }
+
+    fn description(&self) -> &'static str {
+        "Unconditional MERGE clauses should come after conditional ones."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+The first `WHEN MATCHED` clause applies to every matched row, so the second
+clause can never be reached.
+
+```sql
+MERGE INTO target AS t
+USING source AS s
+ON t.id = s.id
+WHEN MATCHED THEN
+    UPDATE SET t.value = s.value
+WHEN MATCHED AND s.deleted THEN
+    DELETE
+```
+
+**Best practice**
+
+Put the conditional clauses first, with at most one unconditional clause of
+each kind at the end.
+
+```sql
+MERGE INTO target AS t
+USING source AS s
+ON t.id = s.id
+WHEN MATCHED AND s.deleted THEN
+    DELETE
+WHEN MATCHED THEN
+    UPDATE SET t.value = s.value
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let clauses = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| {
+                matches!(
+                    it.get_type(),
+                    SyntaxKind::MergeWhenMatchedClause
+                        | SyntaxKind::MergeWhenNotMatchedClause
+                        | SyntaxKind::NotMatchedByTargetClause
+                )
+            })
+            .map(|clause| {
+                let (kind, conditional) = clause_kind(clause);
+                (clause, kind, conditional)
+            })
+            .collect_vec();
+
+        let mut results = Vec::new();
+        for (idx, (clause, kind, conditional)) in clauses.iter().enumerate() {
+            if *conditional {
+                continue;
+            }
+
+            if clauses[idx + 1..]
+                .iter()
+                .any(|(_, later_kind, _)| later_kind == kind)
+            {
+                results.push(LintResult::new(
+                    Some((*clause).clone()),
+                    Vec::new(),
+                    Some(format!(
+                        "Unconditional '{kind}' clause makes later '{kind}' clauses unreachable."
+                    )),
+                    None,
+                ));
+            }
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::MergeMatch]) }).into()
+    }
+}
+
+/// Returns the normalised keywords introducing the clause, e.g. `WHEN NOT MATCHED`,
+/// and whether the clause has an `AND` condition.
+fn clause_kind(clause: &ErasedSegment) -> (String, bool) {
+    let mut keywords = Vec::new();
+    let mut conditional = false;
+
+    for segment in clause.segments().iter().filter(|it| it.is_code()) {
+        if segment.is_keyword("AND") {
+            conditional = true;
+            break;
+        }
+        if !segment.is_type(SyntaxKind::Keyword) || segment.is_keyword("THEN") {
+            break;
+        }
+        keywords.push(segment.raw().to_uppercase());
+    }
+
+    // `WHEN NOT MATCHED BY TARGET` is the same as `WHEN NOT MATCHED`.
+    if keywords.ends_with(&["BY".to_string(), "TARGET".to_string()]) {
+        keywords.truncate(keywords.len() - 2);
+    }
+
+    (keywords.join(" "), conditional)
+}
@@ -0,0 +1,197 @@
+use std::hash::{Hash, Hasher};
+
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone)]
+pub struct RuleAM10 {
+    min_occurrences: usize,
+}
+
+impl Default for RuleAM10 {
+    fn default() -> Self {
+        Self { min_occurrences: 2 }
+    }
+}
+
+impl Rule for RuleAM10 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let min_occurrences = config["min_occurrences"]
+            .as_int()
+            .ok_or("min_occurrences must be an integer")?;
+
+        if min_occurrences < 2 {
+            return Err("min_occurrences must be at least 2".into());
+        }
+
+        Ok(RuleAM10 {
+            min_occurrences: min_occurrences as usize,
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.duplicate_predicates"
+    }
+
+    fn description(&self) -> &'static str {
+        "Boolean expressions should not repeat the same predicate."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+The same predicate appears more than once in a chain of `AND` or `OR`
+conditions. This is redundant, and often the result of a copy-paste error.
+
+```sql
+SELECT *
+FROM orders
+WHERE status = 'open' AND region = 'EU' AND status = 'open'
+```
+
+**Best practice**
+
+Remove the repeated predicate, or correct it to the intended condition.
+
+```sql
+SELECT *
+FROM orders
+WHERE status = 'open' AND region = 'EU'
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let children = context.segment.segments();
+
+        // Split the expression into the operands of its top level AND/OR
+        // chain. Mixing the two operators makes removal change precedence,
+        // so such expressions are left alone.
+        let mut operator = None;
+        let mut operands: Vec<(usize, usize)> = Vec::new();
+        let mut current: Option<(usize, usize)> = None;
+
+        for (idx, child) in children.iter().enumerate() {
+            if is_boolean_operator(child) {
+                let raw = child.raw().to_uppercase();
+                match &operator {
+                    None => operator = Some(raw),
+                    Some(existing) if *existing != raw => return Vec::new(),
+                    Some(_) => {}
+                }
+
+                let Some(operand) = current.take() else {
+                    return Vec::new();
+                };
+                operands.push(operand);
+            } else if child.is_code() {
+                current = Some(match current {
+                    Some((start, _)) => (start, idx),
+                    None => (idx, idx),
+                });
+            }
+        }
+
+        if operator.is_none() {
+            return Vec::new();
+        }
+        let Some(last) = current else {
+            return Vec::new();
+        };
+        operands.push(last);
+
+        let mut occurrences: AHashMap<u64, Vec<usize>> = AHashMap::new();
+        for (position, &(start, end)) in operands.iter().enumerate() {
+            occurrences
+                .entry(operand_hash(&children[start..=end]))
+                .or_default()
+                .push(position);
+        }
+
+        let mut duplicates: Vec<usize> = occurrences
+            .into_values()
+            .filter(|positions| positions.len() >= self.min_occurrences)
+            .flat_map(|positions| positions.into_iter().skip(1))
+            .collect();
+        duplicates.sort_unstable();
+
+        duplicates
+            .into_iter()
+            .map(|position| {
+                let (start, end) = operands[position];
+                // Remove everything from the end of the previous operand up
+                // to the end of this one, which includes the operator.
+                let (_, previous_end) = operands[position - 1];
+                let removed = &children[previous_end + 1..=end];
+
+                let fixes = if removed.iter().any(|it| it.is_comment()) {
+                    Vec::new()
+                } else {
+                    removed.iter().cloned().map(LintFix::delete).collect()
+                };
+
+                LintResult::new(
+                    Some(children[start].clone()),
+                    fixes,
+                    Some(format!(
+                        "Predicate `{}` is repeated in this expression.",
+                        operand_raw(&children[start..=end])
+                    )),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Expression]) }).into()
+    }
+}
+
+fn is_boolean_operator(segment: &ErasedSegment) -> bool {
+    segment.is_type(SyntaxKind::BinaryOperator)
+        && (segment.raw().eq_ignore_ascii_case("AND") || segment.raw().eq_ignore_ascii_case("OR"))
+}
+
+/// Hash the code tokens of an operand. Unlike `hash_value` this ignores
+/// position, whitespace and keyword case so that repeated predicates collide.
+fn operand_hash(segments: &[ErasedSegment]) -> u64 {
+    let mut hasher = ahash::AHasher::default();
+    for raw in segments
+        .iter()
+        .flat_map(|it| it.get_raw_segments())
+        .filter(|it| it.is_code())
+    {
+        raw.get_type().hash(&mut hasher);
+        if matches!(
+            raw.get_type(),
+            SyntaxKind::Keyword | SyntaxKind::NullLiteral | SyntaxKind::BooleanLiteral
+        ) {
+            raw.raw().to_uppercase().hash(&mut hasher);
+        } else {
+            raw.raw().hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+fn operand_raw(segments: &[ErasedSegment]) -> String {
+    segments.iter().map(|it| it.raw().as_str()).collect()
+}
@@ -0,0 +1,146 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone)]
+pub struct RuleAM11;
+
+impl Rule for RuleAM11 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleAM11.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.in_subquery_columns"
+    }
+
+    fn description(&self) -> &'static str {
+        "IN subqueries should select as many columns as they are compared with."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+The subquery of an `IN` predicate selects more columns than the value it is
+compared with. This fails at runtime.
+
+```sql
+SELECT *
+FROM orders
+WHERE customer_id IN (SELECT id, name FROM customers)
+```
+
+**Best practice**
+
+Select a single column, or compare a row of matching width.
+
+```sql
+SELECT *
+FROM orders
+WHERE customer_id IN (SELECT id FROM customers)
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let code: Vec<&ErasedSegment> = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .collect();
+
+        let mut results = Vec::new();
+        for (idx, segment) in code.iter().enumerate() {
+            if !segment.is_keyword("IN") {
+                continue;
+            }
+
+            let Some(&subquery) = code
+                .get(idx + 1)
+                .filter(|it| it.is_type(SyntaxKind::Bracketed))
+            else {
+                continue;
+            };
+
+            let Some(columns) = subquery_column_count(subquery) else {
+                continue;
+            };
+
+            let operand = code[..idx].iter().rev().find(|it| !it.is_keyword("NOT"));
+            let width = operand.map_or(1, |it| row_width(it));
+
+            if columns != width {
+                results.push(LintResult::new(
+                    Some(subquery.clone()),
+                    Vec::new(),
+                    Some(format!(
+                        "IN subquery selects {columns} columns but is compared with {width}."
+                    )),
+                    None,
+                ));
+            }
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Expression]) }).into()
+    }
+}
+
+/// The number of columns selected by a bracketed subquery, if it can be
+/// counted statically.
+fn subquery_column_count(bracketed: &ErasedSegment) -> Option<usize> {
+    let mut query = bracketed.child(
+        const { &SyntaxSet::new(&[SyntaxKind::SelectStatement, SyntaxKind::SetExpression]) },
+    )?;
+
+    // All branches of a set operation share the width of the first.
+    if query.is_type(SyntaxKind::SetExpression) {
+        query = query.child(const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) })?;
+    }
+
+    let elements: Vec<_> = query
+        .child(const { &SyntaxSet::new(&[SyntaxKind::SelectClause]) })?
+        .children(const { &SyntaxSet::new(&[SyntaxKind::SelectClauseElement]) })
+        .cloned()
+        .collect();
+
+    if elements.is_empty()
+        || elements.iter().any(|element| {
+            element
+                .child(const { &SyntaxSet::new(&[SyntaxKind::WildcardExpression]) })
+                .is_some()
+        })
+    {
+        return None;
+    }
+
+    Some(elements.len())
+}
+
+/// The width of the value compared by `IN`, where `(a, b)` is a row of two.
+fn row_width(operand: &ErasedSegment) -> usize {
+    if !operand.is_type(SyntaxKind::Bracketed) {
+        return 1;
+    }
+
+    operand
+        .segments()
+        .iter()
+        .filter(|it| it.is_type(SyntaxKind::Comma))
+        .count()
+        + 1
+}
@@ -0,0 +1,153 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleAM12 {
+    functions: Option<Vec<String>>,
+}
+
+impl RuleAM12 {
+    /// The order sensitive string aggregates of each dialect.
+    fn default_functions(dialect: DialectKind) -> &'static [&'static str] {
+        match dialect {
+            DialectKind::Ansi => &["LISTAGG", "STRING_AGG"],
+            DialectKind::Athena | DialectKind::Trino => &["LISTAGG"],
+            DialectKind::Bigquery => &["STRING_AGG"],
+            DialectKind::Clickhouse => &[],
+            DialectKind::Databricks | DialectKind::Sparksql => &["LISTAGG", "STRING_AGG"],
+            DialectKind::Duckdb => &["GROUP_CONCAT", "LISTAGG", "STRING_AGG"],
+            DialectKind::Mysql => &["GROUP_CONCAT"],
+            DialectKind::Postgres => &["STRING_AGG"],
+            DialectKind::Redshift | DialectKind::Snowflake => &["LISTAGG"],
+            DialectKind::Sqlite => &["GROUP_CONCAT", "STRING_AGG"],
+            DialectKind::Tsql => &["STRING_AGG"],
+        }
+    }
+
+    fn is_checked(&self, dialect: DialectKind, name: &str) -> bool {
+        match &self.functions {
+            Some(functions) => functions.iter().any(|it| it.eq_ignore_ascii_case(name)),
+            None => Self::default_functions(dialect)
+                .iter()
+                .any(|it| it.eq_ignore_ascii_case(name)),
+        }
+    }
+}
+
+impl Rule for RuleAM12 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let functions = config["functions"].as_array().map(|functions| {
+            functions
+                .iter()
+                .filter_map(|it| it.as_string())
+                .map(|it| it.trim().to_uppercase())
+                .filter(|it| !it.is_empty())
+                .collect()
+        });
+
+        Ok(RuleAM12 { functions }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.unordered_aggregate"
+    }
+
+    fn description(&self) -> &'static str {
+        "String aggregates should specify an ordering."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A string aggregate such as `STRING_AGG` or `LISTAGG` is used without an
+`ORDER BY`. The order in which values are concatenated is then not
+deterministic.
+
+```sql
+SELECT
+    customer_id,
+    STRING_AGG(product, ', ') AS products
+FROM orders
+GROUP BY customer_id
+```
+
+**Best practice**
+
+Order the aggregated values, either inside the function call or with a
+`WITHIN GROUP` clause, depending on the dialect.
+
+```sql
+SELECT
+    customer_id,
+    STRING_AGG(product, ', ' ORDER BY product) AS products
+FROM orders
+GROUP BY customer_id
+```
+
+The aggregates checked depend on the dialect, and can be overridden with the
+`functions` configuration option.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let function = &context.segment;
+
+        let Some(name) = function
+            .child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+            .and_then(|it| it.segments().iter().rev().find(|it| it.is_code()).cloned())
+        else {
+            return Vec::new();
+        };
+
+        if !self.is_checked(context.dialect.name, name.raw()) {
+            return Vec::new();
+        }
+
+        let within_group = function
+            .child(const { &SyntaxSet::new(&[SyntaxKind::WithingroupClause]) })
+            .is_some();
+        let order_by = function
+            .child(const { &SyntaxSet::new(&[SyntaxKind::Bracketed]) })
+            .is_some_and(|arguments| {
+                arguments
+                    .child(
+                        const {
+                            &SyntaxSet::new(&[
+                                SyntaxKind::AggregateOrderByClause,
+                                SyntaxKind::OrderbyClause,
+                            ])
+                        },
+                    )
+                    .is_some()
+            });
+
+        if within_group || order_by {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            Some(function.clone()),
+            Vec::new(),
+            Some(format!(
+                "Aggregate `{}` is used without an ORDER BY.",
+                name.raw()
+            )),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Function]) }).into()
+    }
+}
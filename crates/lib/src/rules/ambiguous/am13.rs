@@ -0,0 +1,118 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone)]
+pub struct RuleAM13;
+
+impl Rule for RuleAM13 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleAM13.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.grouping_function"
+    }
+
+    fn description(&self) -> &'static str {
+        "GROUPING should only be used with ROLLUP, CUBE or GROUPING SETS."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+`GROUPING()` tells whether a column was aggregated away by a grouping set. In
+a query grouped by a plain list of columns it is meaningless.
+
+```sql
+SELECT
+    region,
+    GROUPING(region) AS is_total,
+    SUM(amount)
+FROM sales
+GROUP BY region
+```
+
+**Best practice**
+
+Only use `GROUPING()` with `ROLLUP`, `CUBE` or `GROUPING SETS`.
+
+```sql
+SELECT
+    region,
+    GROUPING(region) AS is_total,
+    SUM(amount)
+FROM sales
+GROUP BY ROLLUP (region)
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let select = &context.segment;
+
+        let has_grouping_sets = select
+            .child(const { &SyntaxSet::new(&[SyntaxKind::GroupbyClause]) })
+            .is_some_and(|group_by| {
+                !group_by
+                    .recursive_crawl(
+                        const {
+                            &SyntaxSet::new(&[
+                                SyntaxKind::CubeRollupClause,
+                                SyntaxKind::GroupingSetsClause,
+                                SyntaxKind::WithCubeRollupClause,
+                            ])
+                        },
+                        true,
+                        const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) },
+                        true,
+                    )
+                    .is_empty()
+            });
+        if has_grouping_sets {
+            return Vec::new();
+        }
+
+        select
+            .recursive_crawl(
+                const { &SyntaxSet::new(&[SyntaxKind::Function]) },
+                true,
+                const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) },
+                false,
+            )
+            .into_iter()
+            .filter(|function| {
+                function
+                    .child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+                    .is_some_and(|name| {
+                        name.raw().eq_ignore_ascii_case("GROUPING")
+                            || name.raw().eq_ignore_ascii_case("GROUPING_ID")
+                    })
+            })
+            .map(|function| {
+                LintResult::new(
+                    Some(function),
+                    Vec::new(),
+                    Some(
+                        "GROUPING is used without ROLLUP, CUBE or GROUPING SETS in the GROUP BY."
+                            .into(),
+                    ),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectStatement]) }).into()
+    }
+}
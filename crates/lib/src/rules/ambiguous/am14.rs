@@ -0,0 +1,97 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone)]
+pub struct RuleAM14;
+
+impl Rule for RuleAM14 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleAM14.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.update_from_target"
+    }
+
+    fn description(&self) -> &'static str {
+        "The target of an UPDATE should not be repeated in its FROM clause."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+The table being updated is listed again in the `FROM` clause. T-SQL treats
+this as the target itself, but Postgres and most other engines join a second,
+unrelated copy of the table, so every target row is matched against it.
+
+```sql
+UPDATE orders
+SET status = customers.status
+FROM orders
+INNER JOIN customers ON orders.customer_id = customers.id
+```
+
+**Best practice**
+
+Join only the other tables and correlate them in the `WHERE` clause, or in
+T-SQL update the alias of the table in the `FROM` clause.
+
+```sql
+UPDATE orders
+SET status = customers.status
+FROM customers
+WHERE orders.customer_id = customers.id
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let update = &context.segment;
+
+        let Some(target) = update.child(const { &SyntaxSet::new(&[SyntaxKind::TableReference]) })
+        else {
+            return Vec::new();
+        };
+        let Some(from_clause) = update.child(const { &SyntaxSet::new(&[SyntaxKind::FromClause]) })
+        else {
+            return Vec::new();
+        };
+
+        from_clause
+            .recursive_crawl(
+                const { &SyntaxSet::new(&[SyntaxKind::TableReference]) },
+                true,
+                const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) },
+                false,
+            )
+            .into_iter()
+            .filter(|table| table.raw().eq_ignore_ascii_case(target.raw()))
+            .map(|table| {
+                LintResult::new(
+                    Some(table.clone()),
+                    Vec::new(),
+                    Some(format!(
+                        "UPDATE target `{}` is repeated in the FROM clause, which some dialects \
+                         treat as a self join.",
+                        target.raw()
+                    )),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::UpdateStatement]) }).into()
+    }
+}
@@ -0,0 +1,145 @@
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleAM15 {
+    functions: AHashSet<String>,
+    force_enable: bool,
+}
+
+impl Rule for RuleAM15 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let functions = config["functions"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|it| it.trim().to_uppercase())
+            .filter(|it| !it.is_empty())
+            .collect();
+
+        Ok(RuleAM15 {
+            functions,
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.window_frame"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ordered window aggregates should specify their frame."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+An aggregate is used as a window function with an `ORDER BY` but no frame.
+The frame then defaults to `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT
+ROW`, which treats rows with equal sort keys as peers and gives them all the
+same running total, rather than the row by row total usually expected.
+
+```sql
+SELECT
+    order_date,
+    SUM(amount) OVER (ORDER BY order_date) AS running_total
+FROM orders
+```
+
+**Best practice**
+
+Specify the frame explicitly.
+
+```sql
+SELECT
+    order_date,
+    SUM(amount) OVER (
+        ORDER BY order_date
+        ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+    ) AS running_total
+FROM orders
+```
+
+The aggregates checked can be set with `functions`. Windows referring to a
+named window are not checked. This rule is disabled by default and can be
+enabled with the `force_enable` configuration option.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let function = &context.segment;
+        let Some(function_name) =
+            function.child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+        else {
+            return Vec::new();
+        };
+        if !self.functions.contains(&function_name.raw().to_uppercase()) {
+            return Vec::new();
+        }
+
+        let Some(over_clause) =
+            function.child(const { &SyntaxSet::new(&[SyntaxKind::OverClause]) })
+        else {
+            return Vec::new();
+        };
+        let Some(window) = over_clause
+            .child(const { &SyntaxSet::new(&[SyntaxKind::Bracketed]) })
+            .and_then(|it| it.child(const { &SyntaxSet::new(&[SyntaxKind::WindowSpecification]) }))
+        else {
+            return Vec::new();
+        };
+
+        // The frame may come from the named window being extended.
+        let extends_named_window = window
+            .segments()
+            .iter()
+            .find(|it| it.is_code())
+            .is_some_and(|it| {
+                matches!(
+                    it.get_type(),
+                    SyntaxKind::NakedIdentifier | SyntaxKind::QuotedIdentifier
+                )
+            });
+        if extends_named_window
+            || window
+                .child(const { &SyntaxSet::new(&[SyntaxKind::OrderbyClause]) })
+                .is_none()
+            || window
+                .child(const { &SyntaxSet::new(&[SyntaxKind::FrameClause]) })
+                .is_some()
+        {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            Some(over_clause),
+            Vec::new(),
+            Some(format!(
+                "{}() is ordered without a frame and defaults to RANGE UNBOUNDED PRECEDING, \
+                 consider an explicit ROWS or RANGE frame.",
+                function_name.raw()
+            )),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Function]) }).into()
+    }
+}
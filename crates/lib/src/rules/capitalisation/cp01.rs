@@ -22,6 +22,7 @@ pub struct RuleCP01 {
     pub(crate) ignore_words_regex: Vec<Regex>,
     pub(crate) cap_policy_name: String,
     pub(crate) skip_literals: bool,
+    pub(crate) treat_null_boolean_as_keywords: bool,
     pub(crate) exclude_parent_types: &'static [SyntaxKind],
     pub(crate) description_elem: &'static str,
 }
@@ -32,6 +33,7 @@ impl Default for RuleCP01 {
             capitalisation_policy: "consistent".into(),
             cap_policy_name: "capitalisation_policy".into(),
             skip_literals: true,
+            treat_null_boolean_as_keywords: false,
             exclude_parent_types: &[
                 SyntaxKind::DataType,
                 SyntaxKind::DatetimeTypeIdentifier,
@@ -67,6 +69,9 @@ impl Rule for RuleCP01 {
                         .collect()
                 })
                 .unwrap_or_default(),
+            treat_null_boolean_as_keywords: config["treat_null_boolean_as_keywords"]
+                .as_bool()
+                .unwrap_or_default(),
             ..Default::default()
         }
         .erased())
@@ -111,6 +116,10 @@ select
     a
 from foo
 ```
+
+By default `NULL`, `TRUE` and `FALSE` are treated as literals and are left to
+`capitalisation.literals` (CP04). Set `treat_null_boolean_as_keywords` to make
+them follow the keyword policy instead.
 "#
     }
 
@@ -140,7 +149,15 @@ from foo
             return Vec::new();
         }
 
-        if (self.skip_literals && context.segment.is_type(SyntaxKind::Literal))
+        let is_null_or_boolean = matches!(
+            context.segment.get_type(),
+            SyntaxKind::NullLiteral | SyntaxKind::BooleanLiteral
+        );
+        if is_null_or_boolean {
+            if self.skip_literals && !self.treat_null_boolean_as_keywords {
+                return Vec::new();
+            }
+        } else if (self.skip_literals && context.segment.is_type(SyntaxKind::Literal))
             || !self.exclude_parent_types.is_empty()
                 && self
                     .exclude_parent_types
@@ -174,6 +191,8 @@ from foo
                     SyntaxKind::Keyword,
                     SyntaxKind::BinaryOperator,
                     SyntaxKind::DatePart,
+                    SyntaxKind::NullLiteral,
+                    SyntaxKind::BooleanLiteral,
                 ])
             },
         )
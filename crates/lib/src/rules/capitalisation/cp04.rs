@@ -30,6 +30,7 @@ impl Rule for RuleCP04 {
     fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
         Ok(RuleCP04 {
             base: RuleCP01 {
+                skip_literals: false,
                 capitalisation_policy: config["capitalisation_policy"].as_string().unwrap().into(),
                 ignore_words: config["ignore_words"]
                     .map(|it| {
@@ -11,6 +11,25 @@ pub mod cv08;
 pub mod cv09;
 pub mod cv10;
 pub mod cv11;
+pub mod cv12;
+pub mod cv13;
+pub mod cv14;
+pub mod cv15;
+pub mod cv16;
+pub mod cv17;
+pub mod cv18;
+pub mod cv19;
+pub mod cv20;
+pub mod cv21;
+pub mod cv22;
+pub mod cv23;
+pub mod cv24;
+pub mod cv25;
+pub mod cv26;
+pub mod cv27;
+pub mod cv28;
+pub mod cv29;
+pub mod cv30;
 
 pub fn rules() -> Vec<ErasedRule> {
     use crate::core::rules::base::Erased as _;
@@ -27,5 +46,24 @@ pub fn rules() -> Vec<ErasedRule> {
         cv09::RuleCV09::default().erased(),
         cv10::RuleCV10::default().erased(),
         cv11::RuleCV11::default().erased(),
+        cv12::RuleCV12::default().erased(),
+        cv13::RuleCV13::default().erased(),
+        cv14::RuleCV14.erased(),
+        cv15::RuleCV15::default().erased(),
+        cv16::RuleCV16::default().erased(),
+        cv17::RuleCV17::default().erased(),
+        cv18::RuleCV18.erased(),
+        cv19::RuleCV19::default().erased(),
+        cv20::RuleCV20::default().erased(),
+        cv21::RuleCV21::default().erased(),
+        cv22::RuleCV22::default().erased(),
+        cv23::RuleCV23::default().erased(),
+        cv24::RuleCV24::default().erased(),
+        cv25::RuleCV25::default().erased(),
+        cv26::RuleCV26::default().erased(),
+        cv27::RuleCV27.erased(),
+        cv28::RuleCV28::default().erased(),
+        cv29::RuleCV29::default().erased(),
+        cv30::RuleCV30::default().erased(),
     ]
 }
@@ -0,0 +1,125 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV12 {
+    require_on_delete: bool,
+    forbid_on_delete_cascade: bool,
+}
+
+impl Rule for RuleCV12 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV12 {
+            require_on_delete: config["require_on_delete"].as_bool().unwrap_or_default(),
+            forbid_on_delete_cascade: config["forbid_on_delete_cascade"]
+                .as_bool()
+                .unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.foreign_key_actions"
+    }
+
+    fn description(&self) -> &'static str {
+        "Foreign keys should declare an explicit ON DELETE action."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+Both checks are off by default. Set `require_on_delete` to flag foreign keys
+without an explicit `ON DELETE` action, and `forbid_on_delete_cascade` to flag
+cascading deletes.
+
+**Anti-pattern**
+
+A foreign key relies on the implicit `ON DELETE` behaviour, which for most
+databases is `NO ACTION`.
+
+```sql
+CREATE TABLE orders (
+    id INT PRIMARY KEY,
+    customer_id INT REFERENCES customers (id)
+);
+```
+
+If `forbid_on_delete_cascade` is enabled, cascading deletes are flagged too.
+
+```sql
+CREATE TABLE orders (
+    id INT PRIMARY KEY,
+    customer_id INT REFERENCES customers (id) ON DELETE CASCADE
+);
+```
+
+**Best practice**
+
+State the intended referential action explicitly.
+
+```sql
+CREATE TABLE orders (
+    id INT PRIMARY KEY,
+    customer_id INT REFERENCES customers (id) ON DELETE RESTRICT
+);
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let keywords: Vec<_> = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_type(SyntaxKind::Keyword))
+            .map(|it| it.raw().to_uppercase())
+            .collect();
+
+        if !keywords.iter().any(|it| it == "REFERENCES") {
+            return Vec::new();
+        }
+
+        let on_delete_action = keywords
+            .windows(3)
+            .find(|it| it[0] == "ON" && it[1] == "DELETE")
+            .map(|it| it[2].as_str());
+
+        let description = match on_delete_action {
+            None if self.require_on_delete => {
+                "Foreign key does not specify an explicit ON DELETE action."
+            }
+            Some("CASCADE") if self.forbid_on_delete_cascade => {
+                "Foreign key uses ON DELETE CASCADE."
+            }
+            _ => return Vec::new(),
+        };
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            Vec::new(),
+            Some(description.to_owned()),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(
+            const {
+                SyntaxSet::new(&[
+                    SyntaxKind::TableConstraint,
+                    SyntaxKind::ColumnConstraintSegment,
+                ])
+            },
+        )
+        .into()
+    }
+}
@@ -0,0 +1,117 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV13 {
+    force_enable: bool,
+}
+
+impl Rule for RuleCV13 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV13 {
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.leading_wildcard"
+    }
+
+    fn description(&self) -> &'static str {
+        "LIKE patterns should not start with a wildcard."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled by default and can be enabled with the `force_enable`
+config. Only literal patterns are inspected.
+
+**Anti-pattern**
+
+A pattern starting with `%` or `_` cannot make use of an index on the column.
+
+```sql
+SELECT *
+FROM customers
+WHERE email LIKE '%@example.com'
+```
+
+**Best practice**
+
+Anchor the pattern on its leading characters, or use a dedicated search
+feature of the database.
+
+```sql
+SELECT *
+FROM customers
+WHERE email_domain LIKE 'example.%'
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let code: Vec<&ErasedSegment> = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .collect();
+
+        let mut results = Vec::new();
+        for (idx, segment) in code.iter().enumerate() {
+            if !segment.is_keyword("LIKE") && !segment.is_keyword("ILIKE") {
+                continue;
+            }
+
+            let Some(&pattern) = code.get(idx + 1) else {
+                continue;
+            };
+
+            // Patterns built from expressions, e.g. `'%' || col`, are not literal.
+            let is_literal = pattern.is_type(SyntaxKind::QuotedLiteral)
+                && !code
+                    .get(idx + 2)
+                    .is_some_and(|it| it.is_type(SyntaxKind::BinaryOperator));
+            if !is_literal {
+                continue;
+            }
+
+            let raw = pattern.raw();
+            let Some(quote) = raw.find(['\'', '"']) else {
+                continue;
+            };
+
+            if raw[quote + 1..].starts_with(['%', '_']) {
+                results.push(LintResult::new(
+                    Some(pattern.clone()),
+                    Vec::new(),
+                    Some(format!(
+                        "Pattern {raw} starts with a wildcard and may not be able to use an index."
+                    )),
+                    None,
+                ));
+            }
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Expression]) }).into()
+    }
+}
@@ -0,0 +1,116 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::functional::context::FunctionalContext;
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV14;
+
+impl Rule for RuleCV14 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV14.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.function_arity"
+    }
+
+    fn description(&self) -> &'static str {
+        "Built-in functions should be called with a valid number of arguments."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+The argument counts are checked against a curated list of built-in functions
+kept by each dialect. Unknown and user defined functions are skipped.
+
+**Anti-pattern**
+
+`NULLIF` takes exactly two arguments.
+
+```sql
+SELECT NULLIF(a) AS b
+FROM foo
+```
+
+**Best practice**
+
+```sql
+SELECT NULLIF(a, 0) AS b
+FROM foo
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let Some(function_name) = context
+            .segment
+            .child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+        else {
+            return Vec::new();
+        };
+
+        // Qualified names, e.g. `my_schema.upper(...)`, are user defined functions.
+        if function_name.segments().len() != 1 {
+            return Vec::new();
+        }
+
+        let name = function_name.raw().to_uppercase();
+        let Some((min, max)) = context.dialect.function_arity(&name) else {
+            return Vec::new();
+        };
+
+        let contents = FunctionalContext::new(context)
+            .segment()
+            .children(Some(|it: &ErasedSegment| it.is_type(SyntaxKind::Bracketed)))
+            .children(Some(|it: &ErasedSegment| {
+                it.is_code()
+                    && !matches!(
+                        it.get_type(),
+                        SyntaxKind::StartBracket | SyntaxKind::EndBracket
+                    )
+            }));
+
+        let arguments = if contents.is_empty() {
+            0
+        } else {
+            contents
+                .iter()
+                .filter(|it| it.is_type(SyntaxKind::Comma))
+                .count()
+                + 1
+        };
+
+        if arguments >= min && max.is_none_or(|max| arguments <= max) {
+            return Vec::new();
+        }
+
+        let expected = match max {
+            Some(max) if max == min => format!("{min}"),
+            Some(max) => format!("between {min} and {max}"),
+            None => format!("at least {min}"),
+        };
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            Vec::new(),
+            Some(format!(
+                "Function '{name}' expects {expected} argument(s) but was given {arguments}."
+            )),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Function]) }).into()
+    }
+}
@@ -0,0 +1,127 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::functional::context::FunctionalContext;
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV15 {
+    force_enable: bool,
+}
+
+impl Rule for RuleCV15 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV15 {
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.count_column"
+    }
+
+    fn description(&self) -> &'static str {
+        "Use of COUNT on a single column, which skips NULL values."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled by default and can be enabled with the `force_enable`
+config, as counting the non-null values of a column is often intentional.
+
+**Anti-pattern**
+
+`COUNT(customer_id)` only counts the rows where `customer_id` is not NULL,
+which is easily mistaken for a row count.
+
+```sql
+SELECT COUNT(customer_id) AS orders
+FROM orders
+```
+
+**Best practice**
+
+Use `COUNT(*)` to count rows.
+
+```sql
+SELECT COUNT(*) AS orders
+FROM orders
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let Some(function_name) = context
+            .segment
+            .child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+        else {
+            return Vec::new();
+        };
+
+        if !function_name.raw().eq_ignore_ascii_case("COUNT") {
+            return Vec::new();
+        }
+
+        let arguments = FunctionalContext::new(context)
+            .segment()
+            .children(Some(|it: &ErasedSegment| it.is_type(SyntaxKind::Bracketed)))
+            .children(Some(|it: &ErasedSegment| {
+                it.is_code()
+                    && !matches!(
+                        it.get_type(),
+                        SyntaxKind::StartBracket | SyntaxKind::EndBracket
+                    )
+            }))
+            .into_vec();
+
+        // `COUNT(DISTINCT col)` and `COUNT(a, b)` are left alone.
+        let [argument] = arguments.as_slice() else {
+            return Vec::new();
+        };
+
+        if !is_column_reference(argument) {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            Vec::new(),
+            Some(format!(
+                "'{}' only counts rows where '{}' is not NULL. Use COUNT(*) to count all rows.",
+                context.segment.raw(),
+                argument.raw()
+            )),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Function]) }).into()
+    }
+}
+
+fn is_column_reference(segment: &ErasedSegment) -> bool {
+    if segment.is_type(SyntaxKind::ColumnReference) {
+        return true;
+    }
+
+    if !segment.is_type(SyntaxKind::Expression) {
+        return false;
+    }
+
+    let mut code = segment.segments().iter().filter(|it| it.is_code());
+    matches!((code.next(), code.next()), (Some(inner), None) if is_column_reference(inner))
+}
@@ -0,0 +1,129 @@
+use ahash::AHashMap;
+use regex::Regex;
+use sqruff_lib_core::dialects::syntax::SyntaxKind;
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV16 {
+    path_pattern: Option<Regex>,
+}
+
+impl Rule for RuleCV16 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let path_pattern = match config["path_pattern"].as_string() {
+            Some(pattern) => Some(Regex::new(pattern).map_err(|err| err.to_string())?),
+            None => None,
+        };
+
+        Ok(RuleCV16 { path_pattern }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.transaction_boundaries"
+    }
+
+    fn description(&self) -> &'static str {
+        "Statements in migration files should be wrapped in an explicit transaction."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule only applies to files whose path matches the `path_pattern` regex,
+e.g. `migrations/.*\.sql$`, and is disabled when no pattern is configured.
+
+**Anti-pattern**
+
+The statements are run without an explicit transaction, so a failure part way
+through leaves the migration half applied.
+
+```sql
+ALTER TABLE orders ADD COLUMN shipped_at TIMESTAMP;
+UPDATE orders SET shipped_at = updated_at WHERE status = 'shipped';
+```
+
+**Best practice**
+
+Wrap the statements in `BEGIN` and `COMMIT`.
+
+```sql
+BEGIN;
+ALTER TABLE orders ADD COLUMN shipped_at TIMESTAMP;
+UPDATE orders SET shipped_at = updated_at WHERE status = 'shipped';
+COMMIT;
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let Some(path_pattern) = &self.path_pattern else {
+            return Vec::new();
+        };
+
+        if !context
+            .path
+            .as_deref()
+            .is_some_and(|path| path_pattern.is_match(path))
+        {
+            return Vec::new();
+        }
+
+        let statements: Vec<&ErasedSegment> = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_type(SyntaxKind::Statement))
+            .filter_map(|it| it.segments().iter().find(|it| it.is_code()))
+            .collect();
+
+        let is_transaction = |segment: &ErasedSegment, keywords: &[&str]| {
+            segment.is_type(SyntaxKind::TransactionStatement)
+                && segment
+                    .segments()
+                    .iter()
+                    .find(|it| it.is_code())
+                    .is_some_and(|it| keywords.iter().any(|&keyword| it.is_keyword(keyword)))
+        };
+
+        let Some(first) = statements
+            .iter()
+            .position(|it| !it.is_type(SyntaxKind::TransactionStatement))
+        else {
+            return Vec::new();
+        };
+        let last = statements
+            .iter()
+            .rposition(|it| !it.is_type(SyntaxKind::TransactionStatement))
+            .unwrap();
+
+        let opened = statements[..first]
+            .iter()
+            .any(|it| is_transaction(it, &["BEGIN", "START"]));
+        let closed = statements[last + 1..]
+            .iter()
+            .any(|it| is_transaction(it, &["COMMIT", "END"]));
+
+        if opened && closed {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            Some(statements[0].clone()),
+            Vec::new(),
+            Some("Statements in this file should be wrapped in BEGIN ... COMMIT.".into()),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
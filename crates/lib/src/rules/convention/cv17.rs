@@ -0,0 +1,103 @@
+use ahash::AHashMap;
+use regex::Regex;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV17 {
+    header_pattern: Option<Regex>,
+}
+
+impl Rule for RuleCV17 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let header_pattern = match config["header_pattern"].as_string() {
+            Some(pattern) => Some(Regex::new(pattern).map_err(|err| err.to_string())?),
+            None => None,
+        };
+
+        Ok(RuleCV17 { header_pattern }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.file_header"
+    }
+
+    fn description(&self) -> &'static str {
+        "Files should start with a header comment."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled unless a `header_pattern` regex is configured. The
+comments at the start of the file are joined with newlines and must match the
+pattern, e.g. `Author: .+\nDescription: .+`.
+
+**Anti-pattern**
+
+The file has no header.
+
+```sql
+SELECT id
+FROM orders
+```
+
+**Best practice**
+
+Describe the file in a header comment.
+
+```sql
+-- Author: data-team
+-- Description: List of order ids.
+SELECT id
+FROM orders
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let Some(header_pattern) = &self.header_pattern else {
+            return Vec::new();
+        };
+
+        let raw_segments = context.segment.get_raw_segments();
+        if !raw_segments.iter().any(|it| it.is_code()) {
+            return Vec::new();
+        }
+
+        let header = raw_segments
+            .iter()
+            .take_while(|it| !it.is_code())
+            .filter(|it| it.is_comment())
+            .map(|it| it.raw().trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if !header.is_empty() && header_pattern.is_match(&header) {
+            return Vec::new();
+        }
+
+        let description = if header.is_empty() {
+            "File does not start with a header comment."
+        } else {
+            "File header does not match the configured header pattern."
+        };
+
+        vec![LintResult::new(
+            Some(raw_segments[0].clone()),
+            Vec::new(),
+            Some(description.into()),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
@@ -0,0 +1,135 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::functional::context::FunctionalContext;
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV18;
+
+impl Rule for RuleCV18 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV18.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.extract_field"
+    }
+
+    fn description(&self) -> &'static str {
+        "EXTRACT should only be used with datetime fields known to the dialect."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+The fields are checked against the datetime units of the configured dialect,
+so this catches fields which only exist in other databases.
+
+**Anti-pattern**
+
+`DAYOFWEEK` is not a datetime field in ANSI SQL.
+
+```sql
+SELECT EXTRACT(dayofweek FROM created_at) AS weekday
+FROM orders
+```
+
+**Best practice**
+
+Use a field supported by the dialect.
+
+```sql
+SELECT EXTRACT(weekday FROM created_at) AS weekday
+FROM orders
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let Some(function_name) = context
+            .segment
+            .child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+        else {
+            return Vec::new();
+        };
+
+        if !function_name.raw().eq_ignore_ascii_case("EXTRACT") {
+            return Vec::new();
+        }
+
+        let contents = FunctionalContext::new(context)
+            .segment()
+            .children(Some(|it: &ErasedSegment| it.is_type(SyntaxKind::Bracketed)))
+            .children(Some(|it: &ErasedSegment| {
+                it.is_code() && !it.is_type(SyntaxKind::StartBracket)
+            }))
+            .into_vec();
+
+        let [field, keyword, ..] = contents.as_slice() else {
+            return Vec::new();
+        };
+
+        if !keyword.is_keyword("FROM") || field.is_type(SyntaxKind::DatePart) {
+            return Vec::new();
+        }
+
+        let Some(name) = field_name(field) else {
+            return Vec::new();
+        };
+
+        let name = name.to_uppercase();
+        if context
+            .dialect
+            .sets("datetime_units")
+            .contains(name.as_str())
+            || context
+                .dialect
+                .sets("extended_datetime_units")
+                .contains(name.as_str())
+        {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            Some(field.clone()),
+            Vec::new(),
+            Some(format!(
+                "'{}' is not a datetime field known to the {} dialect.",
+                field.raw(),
+                context.dialect.name.as_ref()
+            )),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Function]) }).into()
+    }
+}
+
+/// The field name, if the field is a bare identifier or string rather than an
+/// arbitrary expression.
+fn field_name(segment: &ErasedSegment) -> Option<String> {
+    match segment.get_type() {
+        SyntaxKind::ColumnReference if segment.segments().len() == 1 => {
+            Some(segment.raw().to_string())
+        }
+        SyntaxKind::QuotedLiteral => Some(segment.raw().trim_matches(['\'', '"']).to_string()),
+        SyntaxKind::Expression => {
+            let mut code = segment.segments().iter().filter(|it| it.is_code());
+            match (code.next(), code.next()) {
+                (Some(inner), None) => field_name(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
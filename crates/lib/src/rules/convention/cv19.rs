@@ -0,0 +1,108 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::SegmentBuilder;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV19 {
+    forbid_trailing_point: bool,
+}
+
+impl Rule for RuleCV19 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV19 {
+            forbid_trailing_point: config["forbid_trailing_point"]
+                .as_bool()
+                .unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.numeric_literal_zero"
+    }
+
+    fn description(&self) -> &'static str {
+        "Decimal literals should have a leading zero."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+Set `forbid_trailing_point` to also require a digit after the decimal point,
+e.g. `5.0` instead of `5.`. Literals in scientific notation are not checked.
+
+**Anti-pattern**
+
+```sql
+SELECT amount * .5 AS half
+FROM orders
+```
+
+**Best practice**
+
+```sql
+SELECT amount * 0.5 AS half
+FROM orders
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        // Signed literals are nodes wrapping the unsigned literal.
+        if !context.segment.segments().is_empty() {
+            return Vec::new();
+        }
+
+        let raw = context.segment.raw();
+        if raw.contains(['e', 'E']) {
+            return Vec::new();
+        }
+
+        let (fixed, description) = if raw.starts_with('.') {
+            (
+                format!("0{raw}"),
+                "Decimal literal should have a leading zero.",
+            )
+        } else if self.forbid_trailing_point && raw.ends_with('.') {
+            (
+                format!("{raw}0"),
+                "Decimal literal should not end with a decimal point.",
+            )
+        } else {
+            return Vec::new();
+        };
+
+        vec![LintResult::new(
+            Some(context.segment.clone()),
+            vec![LintFix::replace(
+                context.segment.clone(),
+                vec![SegmentBuilder::token(
+                    context.tables.next_id(),
+                    &fixed,
+                    SyntaxKind::NumericLiteral,
+                )
+                .finish()],
+                None,
+            )],
+            Some(description.into()),
+            None,
+        )]
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::NumericLiteral]) }).into()
+    }
+}
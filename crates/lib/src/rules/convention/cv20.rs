@@ -0,0 +1,249 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::SegmentBuilder;
+use strum_macros::{AsRefStr, EnumString};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Copy, Clone, AsRefStr, EnumString, Default)]
+#[strum(serialize_all = "snake_case")]
+enum PreferredQuoteEscapeStyle {
+    #[default]
+    Consistent,
+    Doubled,
+    Backslash,
+}
+
+impl PreferredQuoteEscapeStyle {
+    /// The escape to use, or `None` to follow the first one found in the file.
+    fn quote_escape(self) -> Option<QuoteEscape> {
+        match self {
+            PreferredQuoteEscapeStyle::Consistent => None,
+            PreferredQuoteEscapeStyle::Doubled => Some(QuoteEscape::Doubled),
+            PreferredQuoteEscapeStyle::Backslash => Some(QuoteEscape::Backslash),
+        }
+    }
+}
+
+/// How a single quote is escaped within a string literal.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum QuoteEscape {
+    Doubled,
+    Backslash,
+}
+
+impl QuoteEscape {
+    fn escaped_quote(self) -> &'static str {
+        match self {
+            QuoteEscape::Doubled => "''",
+            QuoteEscape::Backslash => "\\'",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RuleCV20 {
+    preferred_quote_escape_style: PreferredQuoteEscapeStyle,
+}
+
+impl Rule for RuleCV20 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV20 {
+            preferred_quote_escape_style: config["preferred_quote_escape_style"]
+                .as_string()
+                .unwrap_or_default()
+                .parse()
+                .map_err(|_| {
+                    "preferred_quote_escape_style must be one of consistent, doubled or backslash"
+                        .to_string()
+                })?,
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.quote_escapes"
+    }
+
+    fn description(&self) -> &'static str {
+        "Consistent escaping of quotes within string literals."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Some dialects (e.g. Snowflake, ClickHouse and MySQL) allow a single quote
+inside a string literal to be escaped either by doubling it or with a
+backslash. Mixing the two styles makes queries harder to read.
+
+```sql
+SELECT
+    'it''s',
+    'it\'s'
+FROM foo
+```
+
+**Best practice**
+
+Escape quotes with a single style, set by `preferred_quote_escape_style`. The
+rule only applies to dialects which support both styles, so that a rewrite
+never changes the meaning of a literal.
+
+```sql
+SELECT
+    'it''s',
+    'it''s'
+FROM foo
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !supports_doubled_quotes(context.dialect.name)
+            || !supports_backslash_escapes(context.dialect.name)
+        {
+            return Vec::new();
+        }
+
+        let raw = context.segment.raw().as_str();
+        // Prefixed, triple quoted and double quoted literals are left alone.
+        if raw.len() < 2 || !raw.starts_with('\'') || !raw.ends_with('\'') || raw.starts_with("'''")
+        {
+            return Vec::new();
+        }
+
+        let Some(pieces) = split_escapes(&raw[1..raw.len() - 1]) else {
+            return Vec::new();
+        };
+
+        let Some(first_style) = pieces.iter().find_map(|piece| match piece {
+            Piece::Quote(style) => Some(*style),
+            Piece::Text(_) => None,
+        }) else {
+            return Vec::new();
+        };
+
+        let preferred_quote_escape = match self.preferred_quote_escape_style.quote_escape() {
+            Some(quote_escape) => quote_escape,
+            None => {
+                let quote_escape = context.try_get::<QuoteEscape>().unwrap_or(first_style);
+                context.set(quote_escape);
+                quote_escape
+            }
+        };
+
+        if !pieces
+            .iter()
+            .any(|piece| matches!(piece, Piece::Quote(style) if *style != preferred_quote_escape))
+        {
+            return Vec::new();
+        }
+
+        let mut fixed = String::from("'");
+        for piece in &pieces {
+            match piece {
+                Piece::Text(text) => fixed.push_str(text),
+                Piece::Quote(_) => fixed.push_str(preferred_quote_escape.escaped_quote()),
+            }
+        }
+        fixed.push('\'');
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            vec![LintFix::replace(
+                context.segment.clone(),
+                vec![SegmentBuilder::token(
+                    context.tables.next_id(),
+                    &fixed,
+                    SyntaxKind::QuotedLiteral,
+                )
+                .finish()],
+                None,
+            )],
+            Some(format!(
+                "Use `{}` to escape quotes in string literals.",
+                preferred_quote_escape.escaped_quote()
+            )),
+            None,
+        )]
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::QuotedLiteral]) }).into()
+    }
+}
+
+enum Piece<'a> {
+    Text(&'a str),
+    Quote(QuoteEscape),
+}
+
+/// Split the body of a single quoted literal into escaped quotes and the text
+/// between them. Other backslash escapes are kept verbatim. Returns `None` if
+/// the body contains an unescaped quote.
+fn split_escapes(body: &str) -> Option<Vec<Piece<'_>>> {
+    let mut pieces = Vec::new();
+    let mut text_start = 0;
+    let mut chars = body.char_indices();
+
+    while let Some((idx, ch)) = chars.next() {
+        let style = match ch {
+            '\\' => match chars.next()? {
+                (_, '\'') => QuoteEscape::Backslash,
+                _ => continue,
+            },
+            '\'' => match chars.next()? {
+                (_, '\'') => QuoteEscape::Doubled,
+                _ => return None,
+            },
+            _ => continue,
+        };
+
+        if text_start < idx {
+            pieces.push(Piece::Text(&body[text_start..idx]));
+        }
+        pieces.push(Piece::Quote(style));
+        text_start = idx + 2;
+    }
+
+    if text_start < body.len() {
+        pieces.push(Piece::Text(&body[text_start..]));
+    }
+
+    Some(pieces)
+}
+
+fn supports_doubled_quotes(dialect: DialectKind) -> bool {
+    !matches!(
+        dialect,
+        DialectKind::Bigquery | DialectKind::Databricks | DialectKind::Sparksql
+    )
+}
+
+/// Postgres and the dialects derived from it follow
+/// `standard_conforming_strings`, where a backslash is an ordinary character.
+fn supports_backslash_escapes(dialect: DialectKind) -> bool {
+    matches!(
+        dialect,
+        DialectKind::Bigquery
+            | DialectKind::Clickhouse
+            | DialectKind::Databricks
+            | DialectKind::Mysql
+            | DialectKind::Snowflake
+            | DialectKind::Sparksql
+    )
+}
@@ -0,0 +1,137 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::SegmentBuilder;
+use strum_macros::{AsRefStr, EnumString};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Copy, Clone, AsRefStr, EnumString, PartialEq, Default)]
+#[strum(serialize_all = "snake_case")]
+enum CteAsKeyword {
+    #[default]
+    Explicit,
+    Implicit,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RuleCV21 {
+    cte_as_keyword: CteAsKeyword,
+}
+
+impl Rule for RuleCV21 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV21 {
+            cte_as_keyword: config["cte_as_keyword"]
+                .as_string()
+                .unwrap()
+                .parse()
+                .map_err(|_| "cte_as_keyword must be either explicit or implicit".to_string())?,
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.cte_as"
+    }
+
+    fn description(&self) -> &'static str {
+        "Consistent use of the AS keyword in common table expressions."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+The `AS` keyword between a CTE name and its body is optional in some dialects.
+Omitting it in some CTEs but not others is inconsistent.
+
+```sql
+WITH
+    orders AS (SELECT * FROM raw_orders),
+    customers (SELECT * FROM raw_customers)
+SELECT * FROM orders JOIN customers USING (customer_id)
+```
+
+**Best practice**
+
+Follow the `cte_as_keyword` policy, which by default always includes `AS`.
+
+```sql
+WITH
+    orders AS (SELECT * FROM raw_orders),
+    customers AS (SELECT * FROM raw_customers)
+SELECT * FROM orders JOIN customers USING (customer_id)
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let children = context.segment.segments();
+
+        let Some(body_idx) = children
+            .iter()
+            .rposition(|it| it.is_type(SyntaxKind::Bracketed))
+        else {
+            return Vec::new();
+        };
+        let as_idx = children[..body_idx]
+            .iter()
+            .position(|it| it.is_keyword("AS"));
+
+        match (self.cte_as_keyword, as_idx) {
+            (CteAsKeyword::Explicit, None) => {
+                let body = &children[body_idx];
+                let mut edit = vec![
+                    SegmentBuilder::keyword(context.tables.next_id(), "AS"),
+                    SegmentBuilder::whitespace(context.tables.next_id(), " "),
+                ];
+                if !children[..body_idx]
+                    .last()
+                    .is_some_and(|it| it.is_whitespace())
+                {
+                    edit.insert(0, SegmentBuilder::whitespace(context.tables.next_id(), " "));
+                }
+
+                vec![LintResult::new(
+                    Some(body.clone()),
+                    vec![LintFix::create_before(body.clone(), edit)],
+                    Some("Missing AS keyword before the CTE body.".into()),
+                    None,
+                )]
+            }
+            (CteAsKeyword::Implicit, Some(as_idx)) => {
+                let removed = &children[as_idx..body_idx];
+                let fixes = if removed.iter().any(|it| it.is_comment()) {
+                    Vec::new()
+                } else {
+                    removed.iter().cloned().map(LintFix::delete).collect()
+                };
+
+                vec![LintResult::new(
+                    Some(children[as_idx].clone()),
+                    fixes,
+                    Some("Unnecessary AS keyword before the CTE body.".into()),
+                    None,
+                )]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::CommonTableExpression]) })
+            .into()
+    }
+}
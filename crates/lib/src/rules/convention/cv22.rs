@@ -0,0 +1,270 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::{ErasedSegment, SegmentBuilder};
+use strum_macros::{AsRefStr, EnumString};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Copy, Clone, AsRefStr, EnumString, PartialEq, Default)]
+#[strum(serialize_all = "snake_case")]
+enum BooleanComparison {
+    #[default]
+    Explicit,
+    Implicit,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RuleCV22 {
+    boolean_comparison: BooleanComparison,
+    force_enable: bool,
+}
+
+impl Rule for RuleCV22 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV22 {
+            boolean_comparison: config["boolean_comparison"]
+                .as_string()
+                .unwrap()
+                .parse()
+                .map_err(|_| {
+                    "boolean_comparison must be either explicit or implicit".to_string()
+                })?,
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.boolean_comparison"
+    }
+
+    fn description(&self) -> &'static str {
+        "Consistent comparison of boolean columns in predicates."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A boolean column is used directly as a `WHERE` or `ON` predicate, which
+relies on the reader knowing the type of the column.
+
+```sql
+SELECT *
+FROM users
+WHERE is_active AND NOT is_deleted
+```
+
+**Best practice**
+
+Compare the column with a boolean literal. With `boolean_comparison` set to
+`implicit` the opposite is enforced, and comparisons with `TRUE` or `FALSE`
+are flagged instead.
+
+This rule is disabled by default and can be enabled with the `force_enable`
+configuration option.
+
+```sql
+SELECT *
+FROM users
+WHERE is_active = TRUE AND NOT is_deleted = TRUE
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let Some(predicate) = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .nth(1)
+        else {
+            return Vec::new();
+        };
+
+        let mut operands = Vec::new();
+        collect_operands(&context.segment, predicate, &mut operands);
+
+        operands
+            .into_iter()
+            .filter_map(|(parent, operand)| match self.boolean_comparison {
+                BooleanComparison::Explicit => explicit_violation(context, operand),
+                BooleanComparison::Implicit => implicit_violation(context, &parent, operand),
+            })
+            .collect()
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(
+            const { SyntaxSet::new(&[SyntaxKind::WhereClause, SyntaxKind::JoinOnCondition]) },
+        )
+        .into()
+    }
+}
+
+/// Collect the operands of the AND/OR chains making up a predicate, looking
+/// through brackets and leading `NOT`s. Each operand is paired with the
+/// segment containing it.
+fn collect_operands(
+    parent: &ErasedSegment,
+    segment: &ErasedSegment,
+    operands: &mut Vec<(ErasedSegment, Vec<ErasedSegment>)>,
+) {
+    let (parent, code): (&ErasedSegment, Vec<ErasedSegment>) = match segment.get_type() {
+        SyntaxKind::Expression => (
+            segment,
+            segment
+                .segments()
+                .iter()
+                .filter(|it| it.is_code())
+                .cloned()
+                .collect(),
+        ),
+        SyntaxKind::Bracketed => {
+            let inner: Vec<_> = segment
+                .segments()
+                .iter()
+                .filter(|it| {
+                    it.is_code()
+                        && !matches!(
+                            it.get_type(),
+                            SyntaxKind::StartBracket | SyntaxKind::EndBracket
+                        )
+                })
+                .collect();
+            if let [inner] = inner.as_slice() {
+                collect_operands(segment, inner, operands);
+            }
+            return;
+        }
+        _ => (parent, vec![segment.clone()]),
+    };
+
+    for operand in code.split(|it| {
+        it.is_type(SyntaxKind::BinaryOperator)
+            && (it.raw().eq_ignore_ascii_case("AND") || it.raw().eq_ignore_ascii_case("OR"))
+    }) {
+        let start = operand
+            .iter()
+            .position(|it| !it.is_keyword("NOT"))
+            .unwrap_or(operand.len());
+        let operand = &operand[start..];
+
+        match operand {
+            [single]
+                if matches!(
+                    single.get_type(),
+                    SyntaxKind::Expression | SyntaxKind::Bracketed
+                ) =>
+            {
+                collect_operands(parent, single, operands)
+            }
+            [] => {}
+            _ => operands.push((parent.clone(), operand.to_vec())),
+        }
+    }
+}
+
+fn explicit_violation(context: &RuleContext, operand: Vec<ErasedSegment>) -> Option<LintResult> {
+    let [column] = operand.as_slice() else {
+        return None;
+    };
+    if !column.is_type(SyntaxKind::ColumnReference) {
+        return None;
+    }
+
+    let comparison = vec![
+        SegmentBuilder::whitespace(context.tables.next_id(), " "),
+        SegmentBuilder::node(
+            context.tables.next_id(),
+            SyntaxKind::ComparisonOperator,
+            context.dialect.name,
+            vec![SegmentBuilder::token(
+                context.tables.next_id(),
+                "=",
+                SyntaxKind::RawComparisonOperator,
+            )
+            .finish()],
+        )
+        .finish(),
+        SegmentBuilder::whitespace(context.tables.next_id(), " "),
+        SegmentBuilder::token(context.tables.next_id(), "TRUE", SyntaxKind::BooleanLiteral)
+            .finish(),
+    ];
+
+    Some(LintResult::new(
+        Some(column.clone()),
+        vec![LintFix::create_after(column.clone(), comparison, None)],
+        Some(format!(
+            "Compare boolean column `{}` explicitly.",
+            column.raw()
+        )),
+        None,
+    ))
+}
+
+fn implicit_violation(
+    context: &RuleContext,
+    parent: &ErasedSegment,
+    operand: Vec<ErasedSegment>,
+) -> Option<LintResult> {
+    let [column, operator, literal] = operand.as_slice() else {
+        return None;
+    };
+    if !column.is_type(SyntaxKind::ColumnReference)
+        || !operator.is_type(SyntaxKind::ComparisonOperator)
+        || operator.raw() != "="
+        || !literal.is_type(SyntaxKind::BooleanLiteral)
+    {
+        return None;
+    }
+
+    // Remove everything after the column, up to and including the literal.
+    let siblings = parent.segments();
+    let start = siblings.iter().position(|it| it == column)? + 1;
+    let end = siblings.iter().position(|it| it == literal)?;
+    let removed = &siblings[start..=end];
+    if removed.iter().any(|it| it.is_comment()) {
+        return Some(LintResult::new(
+            Some(column.clone()),
+            Vec::new(),
+            Some(format!("Use boolean column `{}` directly.", column.raw())),
+            None,
+        ));
+    }
+
+    let mut fixes: Vec<LintFix> = removed.iter().cloned().map(LintFix::delete).collect();
+    if literal.raw().eq_ignore_ascii_case("FALSE") {
+        fixes.push(LintFix::create_before(
+            column.clone(),
+            vec![
+                SegmentBuilder::keyword(context.tables.next_id(), "NOT"),
+                SegmentBuilder::whitespace(context.tables.next_id(), " "),
+            ],
+        ));
+    }
+
+    Some(LintResult::new(
+        Some(column.clone()),
+        fixes,
+        Some(format!("Use boolean column `{}` directly.", column.raw())),
+        None,
+    ))
+}
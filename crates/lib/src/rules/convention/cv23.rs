@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::SegmentBuilder;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleCV23 {
+    ignore_dialects: Vec<DialectKind>,
+}
+
+impl Rule for RuleCV23 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let ignore_dialects = config["ignore_dialects"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|it| {
+                DialectKind::from_str(it.trim())
+                    .map_err(|_| format!("Unknown dialect in ignore_dialects: {it}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(RuleCV23 { ignore_dialects }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.string_concatenation"
+    }
+
+    fn description(&self) -> &'static str {
+        "Strings should be concatenated with CONCAT or the concatenation operator, not +."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A string literal is combined with `+`. Only some dialects (such as T-SQL)
+treat this as concatenation, elsewhere it is numeric addition and either fails
+or silently casts the string.
+
+```sql
+SELECT first_name + ' ' + last_name AS full_name
+FROM users
+```
+
+**Best practice**
+
+Use the `||` operator or the `CONCAT` function.
+
+```sql
+SELECT first_name || ' ' || last_name AS full_name
+FROM users
+```
+
+Dialects where `+` concatenates strings can be listed in `ignore_dialects`.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if self.ignore_dialects.contains(&context.dialect.name) {
+            return Vec::new();
+        }
+
+        let code: Vec<_> = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .collect();
+
+        let mut results = Vec::new();
+        for (idx, operator) in code.iter().enumerate() {
+            if !operator.is_type(SyntaxKind::BinaryOperator) || operator.raw() != "+" {
+                continue;
+            }
+
+            let is_string = |idx: Option<usize>| {
+                idx.and_then(|idx| code.get(idx))
+                    .is_some_and(|it| it.is_type(SyntaxKind::QuotedLiteral))
+            };
+            if !is_string(idx.checked_sub(1)) && !is_string(Some(idx + 1)) {
+                continue;
+            }
+
+            let concat = SegmentBuilder::node(
+                context.tables.next_id(),
+                SyntaxKind::BinaryOperator,
+                context.dialect.name,
+                vec![
+                    SegmentBuilder::token(context.tables.next_id(), "|", SyntaxKind::Pipe).finish(),
+                    SegmentBuilder::token(context.tables.next_id(), "|", SyntaxKind::Pipe).finish(),
+                ],
+            )
+            .finish();
+
+            results.push(LintResult::new(
+                Some((*operator).clone()),
+                vec![LintFix::replace((*operator).clone(), vec![concat], None)],
+                Some("Use || or CONCAT to concatenate strings instead of +.".into()),
+                None,
+            ));
+        }
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Expression]) }).into()
+    }
+}
@@ -0,0 +1,196 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::{ErasedSegment, SegmentBuilder};
+use strum_macros::{AsRefStr, EnumString};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Copy, Clone, AsRefStr, EnumString, PartialEq, Default)]
+#[strum(serialize_all = "snake_case")]
+enum ExpressionBrackets {
+    #[default]
+    Require,
+    Remove,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RuleCV24 {
+    expression_brackets: ExpressionBrackets,
+    force_enable: bool,
+}
+
+impl Rule for RuleCV24 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV24 {
+            expression_brackets: config["expression_brackets"]
+                .as_string()
+                .unwrap()
+                .parse()
+                .map_err(|_| "expression_brackets must be either require or remove".to_string())?,
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.select_expression_brackets"
+    }
+
+    fn description(&self) -> &'static str {
+        "Consistent use of brackets around computed select expressions."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A select expression combining values with an operator is not wrapped in
+brackets, which makes it harder to see where the expression ends and the
+alias begins.
+
+```sql
+SELECT
+    price * quantity AS total,
+    first_name || ' ' || last_name AS full_name
+FROM orders
+```
+
+**Best practice**
+
+Wrap computed expressions in brackets. Plain column references and function
+calls are left alone. With `expression_brackets` set to `remove` the opposite
+is enforced, and redundant brackets around computed expressions are flagged
+instead.
+
+This rule is disabled by default and can be enabled with the `force_enable`
+configuration option.
+
+```sql
+SELECT
+    (price * quantity) AS total,
+    (first_name || ' ' || last_name) AS full_name
+FROM orders
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let Some(expression) = context
+            .segment
+            .segments()
+            .iter()
+            .find(|it| it.is_code())
+            .filter(|it| it.is_type(SyntaxKind::Expression))
+        else {
+            return Vec::new();
+        };
+
+        let result = match self.expression_brackets {
+            ExpressionBrackets::Require => missing_brackets(context, expression),
+            ExpressionBrackets::Remove => redundant_brackets(expression),
+        };
+        result.into_iter().collect()
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectClauseElement]) })
+            .into()
+    }
+}
+
+/// Whether an expression applies a binary operator outside of any brackets.
+fn is_computed(expression: &ErasedSegment) -> bool {
+    expression
+        .segments()
+        .iter()
+        .any(|it| it.is_type(SyntaxKind::BinaryOperator))
+}
+
+fn missing_brackets(context: &RuleContext, expression: &ErasedSegment) -> Option<LintResult> {
+    if !is_computed(expression) {
+        return None;
+    }
+
+    Some(LintResult::new(
+        Some(expression.clone()),
+        vec![LintFix::replace(
+            expression.clone(),
+            vec![
+                SegmentBuilder::token(context.tables.next_id(), "(", SyntaxKind::StartBracket)
+                    .finish(),
+                expression.clone(),
+                SegmentBuilder::token(context.tables.next_id(), ")", SyntaxKind::EndBracket)
+                    .finish(),
+            ],
+            None,
+        )],
+        Some("Computed select expressions should be wrapped in brackets.".into()),
+        None,
+    ))
+}
+
+fn redundant_brackets(expression: &ErasedSegment) -> Option<LintResult> {
+    let code: Vec<_> = expression
+        .segments()
+        .iter()
+        .filter(|it| it.is_code())
+        .collect();
+    let [bracketed] = code.as_slice() else {
+        return None;
+    };
+    if !bracketed.is_type(SyntaxKind::Bracketed) {
+        return None;
+    }
+
+    let inner: Vec<_> = bracketed
+        .segments()
+        .iter()
+        .filter(|it| {
+            it.is_code()
+                && !matches!(
+                    it.get_type(),
+                    SyntaxKind::StartBracket | SyntaxKind::EndBracket
+                )
+        })
+        .collect();
+    let [inner] = inner.as_slice() else {
+        return None;
+    };
+    if !inner.is_type(SyntaxKind::Expression) || !is_computed(inner) {
+        return None;
+    }
+
+    // Keep any comments inside the brackets rather than dropping them.
+    let fixes = if bracketed.segments().iter().any(|it| it.is_comment()) {
+        Vec::new()
+    } else {
+        vec![LintFix::replace(
+            (*bracketed).clone(),
+            vec![(*inner).clone()],
+            None,
+        )]
+    };
+
+    Some(LintResult::new(
+        Some(expression.clone()),
+        fixes,
+        Some("Computed select expressions should not be wrapped in brackets.".into()),
+        None,
+    ))
+}
@@ -0,0 +1,117 @@
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Clone, Debug, Default)]
+pub struct RuleCV25 {
+    random_functions: AHashSet<String>,
+}
+
+impl Rule for RuleCV25 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let random_functions = config["random_functions"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|it| it.trim().to_uppercase())
+            .filter(|it| !it.is_empty())
+            .collect();
+
+        Ok(RuleCV25 { random_functions }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.random_order"
+    }
+
+    fn description(&self) -> &'static str {
+        "Avoid ordering by a random function to sample rows."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Rows are sampled by ordering on a random value. Every row has to be read and
+sorted before the limit is applied, which is slow on large tables.
+
+```sql
+SELECT *
+FROM events
+ORDER BY RANDOM()
+LIMIT 100
+```
+
+**Best practice**
+
+Use `TABLESAMPLE` where the dialect supports it.
+
+```sql
+SELECT *
+FROM events TABLESAMPLE BERNOULLI (1)
+LIMIT 100
+```
+
+The functions treated as random can be set with `random_functions`.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .filter_map(|key| {
+                let function_name = order_key_function(key)?
+                    .child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })?;
+                if !self
+                    .random_functions
+                    .contains(&function_name.raw().to_uppercase())
+                {
+                    return None;
+                }
+
+                Some(LintResult::new(
+                    Some(key.clone()),
+                    Vec::new(),
+                    Some(format!(
+                        "Ordering by {}() reads and sorts every row, consider TABLESAMPLE \
+                         instead.",
+                        function_name.raw()
+                    )),
+                    None,
+                ))
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::OrderbyClause]) }).into()
+    }
+}
+
+/// The function making up an ORDER BY key, if the key is a bare function call.
+fn order_key_function(key: &ErasedSegment) -> Option<ErasedSegment> {
+    match key.get_type() {
+        SyntaxKind::Function => Some(key.clone()),
+        SyntaxKind::Expression => {
+            let code: Vec<_> = key.segments().iter().filter(|it| it.is_code()).collect();
+            match code.as_slice() {
+                [function] if function.is_type(SyntaxKind::Function) => Some((*function).clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
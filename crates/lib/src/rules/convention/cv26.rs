@@ -0,0 +1,150 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Clone, Debug)]
+pub struct RuleCV26 {
+    max_concat_length: usize,
+    force_enable: bool,
+}
+
+impl Default for RuleCV26 {
+    fn default() -> Self {
+        RuleCV26 {
+            max_concat_length: 4,
+            force_enable: false,
+        }
+    }
+}
+
+impl Rule for RuleCV26 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV26 {
+            max_concat_length: config["max_concat_length"].as_int().unwrap_or(4).max(1) as usize,
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.concat_chain"
+    }
+
+    fn description(&self) -> &'static str {
+        "Long chains of string concatenation should be simplified."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+An expression joins many values with `||` or nested `CONCAT` calls, which is
+hard to read and easy to get the separators wrong in.
+
+```sql
+SELECT
+    street || ', ' || city || ', ' || region || ' ' || postcode AS address
+FROM addresses
+```
+
+**Best practice**
+
+Use `CONCAT_WS` or a formatting function instead.
+
+```sql
+SELECT
+    CONCAT_WS(', ', street, city, region || ' ' || postcode) AS address
+FROM addresses
+```
+
+Each top-level expression is counted separately, and is flagged once it
+performs more than `max_concat_length` concatenations. This rule is disabled
+by default and can be enabled with the `force_enable` configuration option.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        if !is_chain_root(&context.segment) {
+            return Vec::new();
+        }
+
+        // Only count from the outermost expression, stopping at subqueries.
+        for parent in context.parent_stack.iter().rev() {
+            if parent.is_type(SyntaxKind::SelectStatement) {
+                break;
+            }
+            if is_chain_root(parent) {
+                return Vec::new();
+            }
+        }
+
+        let length = concat_length(&context.segment);
+        if length <= self.max_concat_length {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            Some(context.segment.clone()),
+            Vec::new(),
+            Some(format!(
+                "Expression performs {length} concatenations (maximum {}), consider CONCAT_WS \
+                 or a formatting function.",
+                self.max_concat_length
+            )),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(
+            const { SyntaxSet::new(&[SyntaxKind::Expression, SyntaxKind::Function]) },
+        )
+        .into()
+    }
+}
+
+/// The number of `||` operators and `CONCAT` arguments beyond the first within
+/// a segment, not looking into subqueries.
+fn concat_length(segment: &ErasedSegment) -> usize {
+    let own = match segment.get_type() {
+        SyntaxKind::BinaryOperator if segment.raw() == "||" => 1,
+        SyntaxKind::Function if is_concat(segment) => segment
+            .child(const { &SyntaxSet::new(&[SyntaxKind::Bracketed]) })
+            .map_or(0, |arguments| {
+                arguments
+                    .segments()
+                    .iter()
+                    .filter(|it| it.is_type(SyntaxKind::Comma))
+                    .count()
+            }),
+        SyntaxKind::SelectStatement | SyntaxKind::SetExpression => return 0,
+        _ => 0,
+    };
+
+    own + segment.segments().iter().map(concat_length).sum::<usize>()
+}
+
+/// Whether a concatenation chain can start at this segment.
+fn is_chain_root(segment: &ErasedSegment) -> bool {
+    segment.is_type(SyntaxKind::Expression)
+        || (segment.is_type(SyntaxKind::Function) && is_concat(segment))
+}
+
+fn is_concat(function: &ErasedSegment) -> bool {
+    function
+        .child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+        .is_some_and(|name| name.raw().eq_ignore_ascii_case("CONCAT"))
+}
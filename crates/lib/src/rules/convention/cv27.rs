@@ -0,0 +1,105 @@
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleCV27;
+
+impl Rule for RuleCV27 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV27.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.parameter_numbering"
+    }
+
+    fn description(&self) -> &'static str {
+        "Positional parameters should be numbered without gaps."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A statement uses positional parameters such as `$1` and `$3` but skips a
+number, usually because a parameter was removed and the others were not
+renumbered. The caller still has to bind a value for the missing parameter.
+
+```sql
+SELECT id, name
+FROM users
+WHERE id = $1 AND status = $3
+```
+
+**Best practice**
+
+Number the parameters of each statement from `$1` without gaps.
+
+```sql
+SELECT id, name
+FROM users
+WHERE id = $1 AND status = $2
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let parameters: Vec<_> = context
+            .segment
+            .recursive_crawl(
+                const { &SyntaxSet::new(&[SyntaxKind::DollarNumericLiteral]) },
+                true,
+                &SyntaxSet::EMPTY,
+                false,
+            )
+            .into_iter()
+            .filter_map(|parameter| {
+                let number = parameter.raw().strip_prefix('$')?.parse::<usize>().ok()?;
+                Some((number, parameter))
+            })
+            .collect();
+
+        let used: AHashSet<_> = parameters.iter().map(|(number, _)| *number).collect();
+        let mut reported = AHashSet::new();
+
+        parameters
+            .into_iter()
+            .filter(|(number, _)| *number > 1 && !used.contains(&(number - 1)))
+            .filter(|(number, _)| reported.insert(*number))
+            .map(|(number, parameter)| {
+                let first_missing = (1..number)
+                    .rev()
+                    .take_while(|it| !used.contains(it))
+                    .last()
+                    .unwrap_or(number - 1);
+                let missing = if first_missing == number - 1 {
+                    format!("`${first_missing}`")
+                } else {
+                    format!("`${first_missing}` to `${}`", number - 1)
+                };
+
+                LintResult::new(
+                    Some(parameter),
+                    Vec::new(),
+                    Some(format!("Parameter `${number}` is used without {missing}.")),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Statement]) })
+            .disallow_recurse()
+            .into()
+    }
+}
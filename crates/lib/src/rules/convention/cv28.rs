@@ -0,0 +1,188 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+use strum_macros::{AsRefStr, EnumString};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Copy, Clone, AsRefStr, EnumString, PartialEq)]
+#[strum(serialize_all = "snake_case")]
+enum ConstraintKind {
+    NotNull,
+    Null,
+    Default,
+    PrimaryKey,
+    Unique,
+    Check,
+    References,
+    Collate,
+    Other,
+}
+
+impl ConstraintKind {
+    fn of(constraint: &ErasedSegment) -> ConstraintKind {
+        // Skip over the name of a named constraint.
+        let keyword = constraint
+            .segments()
+            .iter()
+            .filter(|it| it.is_type(SyntaxKind::Keyword))
+            .find(|it| !it.raw().eq_ignore_ascii_case("CONSTRAINT"));
+
+        match keyword.map(|it| it.raw().to_uppercase()).as_deref() {
+            Some("NOT") => ConstraintKind::NotNull,
+            Some("NULL") => ConstraintKind::Null,
+            Some("DEFAULT") => ConstraintKind::Default,
+            Some("PRIMARY") => ConstraintKind::PrimaryKey,
+            Some("UNIQUE") => ConstraintKind::Unique,
+            Some("CHECK") => ConstraintKind::Check,
+            Some("REFERENCES") => ConstraintKind::References,
+            Some("COLLATE") => ConstraintKind::Collate,
+            _ => ConstraintKind::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleCV28 {
+    constraint_order: Vec<ConstraintKind>,
+}
+
+impl Default for RuleCV28 {
+    fn default() -> Self {
+        RuleCV28 {
+            constraint_order: vec![
+                ConstraintKind::NotNull,
+                ConstraintKind::Null,
+                ConstraintKind::Default,
+            ],
+        }
+    }
+}
+
+impl Rule for RuleCV28 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let constraint_order = config["constraint_order"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|it| {
+                it.trim()
+                    .parse()
+                    .map_err(|_| format!("Unknown column constraint kind: {it}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(RuleCV28 { constraint_order }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.column_constraint_order"
+    }
+
+    fn description(&self) -> &'static str {
+        "Column constraints should be listed in a consistent order."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+The constraints of each column are listed in whatever order they were
+written, so the same constraints read differently from column to column.
+
+```sql
+CREATE TABLE orders (
+    id INT NOT NULL PRIMARY KEY,
+    status VARCHAR(10) DEFAULT 'open' NOT NULL,
+    amount INT CHECK (amount > 0) DEFAULT 0 NOT NULL
+)
+```
+
+**Best practice**
+
+List the constraints in the order given by `constraint_order`, by default
+`NOT NULL` or `NULL` then `DEFAULT`. Constraints not mentioned keep their
+order after those that are.
+
+```sql
+CREATE TABLE orders (
+    id INT NOT NULL PRIMARY KEY,
+    status VARCHAR(10) NOT NULL DEFAULT 'open',
+    amount INT NOT NULL DEFAULT 0 CHECK (amount > 0)
+)
+```
+
+The kinds which can be listed are `not_null`, `null`, `default`,
+`primary_key`, `unique`, `check`, `references`, `collate` and `other`.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let constraints: Vec<_> = context
+            .segment
+            .children(const { &SyntaxSet::new(&[SyntaxKind::ColumnConstraintSegment]) })
+            .cloned()
+            .collect();
+        if constraints.len() < 2 {
+            return Vec::new();
+        }
+
+        let rank = |constraint: &ErasedSegment| {
+            let kind = ConstraintKind::of(constraint);
+            self.constraint_order
+                .iter()
+                .position(|it| *it == kind)
+                .unwrap_or(self.constraint_order.len())
+        };
+
+        let mut ordered = constraints.clone();
+        ordered.sort_by_key(rank);
+        if ordered == constraints {
+            return Vec::new();
+        }
+
+        // Moving constraints around would separate them from any comments
+        // written between them, or carry along those written inside them.
+        let fixes = if context
+            .segment
+            .get_raw_segments()
+            .iter()
+            .any(|it| it.is_comment())
+        {
+            Vec::new()
+        } else {
+            constraints
+                .iter()
+                .zip(ordered)
+                .filter(|(constraint, replacement)| *constraint != replacement)
+                .map(|(constraint, replacement)| {
+                    LintFix::replace(constraint.clone(), vec![replacement], None)
+                })
+                .collect()
+        };
+
+        vec![LintResult::new(
+            Some(context.segment.clone()),
+            fixes,
+            Some("Column constraints are not in the preferred order.".into()),
+            None,
+        )]
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::ColumnDefinition]) }).into()
+    }
+}
@@ -0,0 +1,166 @@
+use ahash::{AHashMap, AHashSet};
+use regex::Regex;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV29 {
+    name_pattern: Option<Regex>,
+    require_drop: bool,
+}
+
+impl Rule for RuleCV29 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let name_pattern = match config["name_pattern"].as_string() {
+            Some(pattern) => Some(Regex::new(pattern).map_err(|err| err.to_string())?),
+            None => None,
+        };
+
+        Ok(RuleCV29 {
+            name_pattern,
+            require_drop: config["require_drop"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.temporary_tables"
+    }
+
+    fn description(&self) -> &'static str {
+        "Temporary tables should be clearly named and dropped."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled unless `name_pattern` or `require_drop` is configured.
+
+**Anti-pattern**
+
+A script creates a temporary table which reads like a permanent one, and
+leaves it behind for the rest of the session.
+
+```sql
+CREATE TEMPORARY TABLE active_users AS
+SELECT * FROM users WHERE active;
+
+SELECT COUNT(*) FROM active_users;
+```
+
+**Best practice**
+
+Name temporary tables so they stand out, and drop them once they are no
+longer needed.
+
+```sql
+CREATE TEMPORARY TABLE tmp_active_users AS
+SELECT * FROM users WHERE active;
+
+SELECT COUNT(*) FROM tmp_active_users;
+
+DROP TABLE tmp_active_users;
+```
+
+Temporary table names are checked against the `name_pattern` regex, e.g.
+`^tmp_`. With `require_drop` enabled, temporary tables which are not dropped
+later in the same file are flagged too.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if self.name_pattern.is_none() && !self.require_drop {
+            return Vec::new();
+        }
+
+        let statements = context.segment.recursive_crawl(
+            const {
+                &SyntaxSet::new(&[
+                    SyntaxKind::CreateTableStatement,
+                    SyntaxKind::DropTableStatement,
+                ])
+            },
+            true,
+            &SyntaxSet::EMPTY,
+            false,
+        );
+
+        let mut results = Vec::new();
+        for (idx, create) in statements.iter().enumerate() {
+            if !create.is_type(SyntaxKind::CreateTableStatement) || !is_temporary(create) {
+                continue;
+            }
+            let Some(table) =
+                create.child(const { &SyntaxSet::new(&[SyntaxKind::TableReference]) })
+            else {
+                continue;
+            };
+
+            if let Some(pattern) = &self.name_pattern {
+                let name = table
+                    .segments()
+                    .iter()
+                    .rev()
+                    .find(|it| it.is_code())
+                    .map_or_else(|| table.raw().to_string(), |it| it.raw().to_string());
+                if !pattern.is_match(&name) {
+                    results.push(LintResult::new(
+                        Some(table.clone()),
+                        Vec::new(),
+                        Some(format!(
+                            "Temporary table `{}` does not match the pattern `{}`.",
+                            table.raw(),
+                            pattern.as_str()
+                        )),
+                        None,
+                    ));
+                }
+            }
+
+            if self.require_drop {
+                let dropped: AHashSet<_> = statements[idx + 1..]
+                    .iter()
+                    .filter(|it| it.is_type(SyntaxKind::DropTableStatement))
+                    .flat_map(|drop| {
+                        drop.children(const { &SyntaxSet::new(&[SyntaxKind::TableReference]) })
+                            .map(|it| it.raw().to_uppercase())
+                            .collect::<Vec<_>>()
+                    })
+                    .collect();
+                if !dropped.contains(&table.raw().to_uppercase()) {
+                    results.push(LintResult::new(
+                        Some(table.clone()),
+                        Vec::new(),
+                        Some(format!(
+                            "Temporary table `{}` is not dropped in this file.",
+                            table.raw()
+                        )),
+                        None,
+                    ));
+                }
+            }
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
+
+fn is_temporary(create: &ErasedSegment) -> bool {
+    create
+        .segments()
+        .iter()
+        .take_while(|it| !it.is_type(SyntaxKind::TableReference))
+        .any(|it| it.is_keyword("TEMP") || it.is_keyword("TEMPORARY"))
+}
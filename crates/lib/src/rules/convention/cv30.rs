@@ -0,0 +1,184 @@
+use ahash::{AHashMap, AHashSet};
+use regex::Regex;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::SegmentBuilder;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Default, Clone, Debug)]
+pub struct RuleCV30 {
+    path_pattern: Option<Regex>,
+    statement_types: AHashSet<String>,
+}
+
+impl Rule for RuleCV30 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let path_pattern = match config["path_pattern"].as_string() {
+            Some(pattern) => Some(Regex::new(pattern).map_err(|err| err.to_string())?),
+            None => None,
+        };
+        let statement_types = config["statement_types"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|it| it.trim().to_lowercase())
+            .filter(|it| !it.is_empty())
+            .collect();
+
+        Ok(RuleCV30 {
+            path_pattern,
+            statement_types,
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.idempotent_ddl"
+    }
+
+    fn description(&self) -> &'static str {
+        "DDL in migration files should use IF NOT EXISTS and IF EXISTS."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule only applies to files whose path matches the `path_pattern` regex,
+e.g. `migrations/.*\.sql$`, and is disabled when no pattern is configured.
+
+**Anti-pattern**
+
+The migration fails when it is run a second time, because the objects it
+creates already exist or the objects it drops are already gone.
+
+```sql
+CREATE TABLE orders (id INT);
+DROP INDEX orders_legacy_idx;
+```
+
+**Best practice**
+
+Guard the statements with `IF NOT EXISTS` and `IF EXISTS`.
+
+```sql
+CREATE TABLE IF NOT EXISTS orders (id INT);
+DROP INDEX IF EXISTS orders_legacy_idx;
+```
+
+The objects checked are set with `statement_types`, a comma separated list of
+`table`, `view`, `index` and `schema`. `CREATE OR REPLACE` statements are
+already re-runnable and are not flagged.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let Some(path_pattern) = &self.path_pattern else {
+            return Vec::new();
+        };
+
+        if !context
+            .path
+            .as_deref()
+            .is_some_and(|path| path_pattern.is_match(path))
+        {
+            return Vec::new();
+        }
+
+        let (object, is_create) = match context.segment.get_type() {
+            SyntaxKind::CreateTableStatement => ("TABLE", true),
+            SyntaxKind::CreateViewStatement => ("VIEW", true),
+            SyntaxKind::CreateIndexStatement => ("INDEX", true),
+            SyntaxKind::CreateSchemaStatement => ("SCHEMA", true),
+            SyntaxKind::DropTableStatement => ("TABLE", false),
+            SyntaxKind::DropViewStatement => ("VIEW", false),
+            SyntaxKind::DropIndexStatement => ("INDEX", false),
+            SyntaxKind::DropSchemaStatement => ("SCHEMA", false),
+            _ => return Vec::new(),
+        };
+        if !self.statement_types.contains(&object.to_lowercase()) {
+            return Vec::new();
+        }
+
+        let code: Vec<_> = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .collect();
+        if code
+            .iter()
+            .any(|it| it.is_keyword("IF") || it.is_keyword("REPLACE"))
+        {
+            return Vec::new();
+        }
+
+        let Some(mut position) = code.iter().position(|it| it.is_keyword(object)) else {
+            return Vec::new();
+        };
+        // Postgres expects the guard after `CREATE INDEX CONCURRENTLY`.
+        if code
+            .get(position + 1)
+            .is_some_and(|it| it.is_keyword("CONCURRENTLY"))
+        {
+            position += 1;
+        }
+        // Unnamed indexes can't be guarded.
+        if code.get(position + 1).is_some_and(|it| it.is_keyword("ON")) {
+            return Vec::new();
+        }
+
+        let guard: &[&str] = if is_create {
+            &["IF", "NOT", "EXISTS"]
+        } else {
+            &["IF", "EXISTS"]
+        };
+        let mut edit = Vec::with_capacity(guard.len() * 2);
+        for keyword in guard {
+            edit.push(SegmentBuilder::whitespace(context.tables.next_id(), " "));
+            edit.push(SegmentBuilder::keyword(context.tables.next_id(), keyword));
+        }
+
+        let statement = code[0];
+        vec![LintResult::new(
+            Some(statement.clone()),
+            vec![LintFix::create_after(code[position].clone(), edit, None)],
+            Some(format!(
+                "{} {} without {} is not re-runnable.",
+                statement.raw().to_uppercase(),
+                object,
+                guard.join(" ")
+            )),
+            None,
+        )]
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(
+            const {
+                SyntaxSet::new(&[
+                    SyntaxKind::CreateTableStatement,
+                    SyntaxKind::CreateViewStatement,
+                    SyntaxKind::CreateIndexStatement,
+                    SyntaxKind::CreateSchemaStatement,
+                    SyntaxKind::DropTableStatement,
+                    SyntaxKind::DropViewStatement,
+                    SyntaxKind::DropIndexStatement,
+                    SyntaxKind::DropSchemaStatement,
+                ])
+            },
+        )
+        .into()
+    }
+}
@@ -13,6 +13,9 @@ pub mod lt10;
 pub mod lt11;
 pub mod lt12;
 pub mod lt13;
+pub mod lt14;
+pub mod lt15;
+pub mod lt16;
 
 pub fn rules() -> Vec<ErasedRule> {
     use crate::core::rules::base::Erased as _;
@@ -31,5 +34,8 @@ pub fn rules() -> Vec<ErasedRule> {
         lt11::RuleLT11.erased(),
         lt12::RuleLT12.erased(),
         lt13::RuleLT13.erased(),
+        lt14::RuleLT14::default().erased(),
+        lt15::RuleLT15::default().erased(),
+        lt16::RuleLT16::default().erased(),
     ]
 }
@@ -0,0 +1,150 @@
+use ahash::AHashMap;
+use itertools::Itertools;
+use sqruff_lib_core::dialects::syntax::SyntaxKind;
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::{ErasedSegment, SegmentBuilder};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleLT14 {
+    force_enable: bool,
+    allow_compact: bool,
+}
+
+impl Rule for RuleLT14 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleLT14 {
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+            allow_compact: config["allow_compact"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "layout.comma_spacing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Commas should be followed by a single space and not preceded by whitespace."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled by default and can be enabled with the `force_enable`
+config, since LT01 already checks the spacing around commas.
+
+Commas at the end of a line are left alone. Set `allow_compact` to also accept
+commas which are directly followed by the next element, e.g. `f(a,b)`.
+
+**Anti-pattern**
+
+```sql
+SELECT
+    a ,b,  c
+FROM foo
+WHERE d IN (1 ,2)
+```
+
+**Best practice**
+
+```sql
+SELECT
+    a, b, c
+FROM foo
+WHERE d IN (1, 2)
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Layout]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let raw_segments = context
+            .segment
+            .get_raw_segments()
+            .into_iter()
+            .filter(|it| !it.is_meta())
+            .collect_vec();
+
+        let mut results = Vec::new();
+        for (idx, comma) in raw_segments.iter().enumerate() {
+            if !comma.is_type(SyntaxKind::Comma) || comma.is_templated() {
+                continue;
+            }
+
+            let mut fixes = Vec::new();
+
+            // Whitespace at the start of a line is indentation, e.g. for leading commas.
+            if let Some(before) = idx.checked_sub(1).map(|it| &raw_segments[it]) {
+                let starts_line = idx < 2 || raw_segments[idx - 2].is_type(SyntaxKind::Newline);
+                if before.is_type(SyntaxKind::Whitespace) && !starts_line {
+                    fixes.push(LintFix::delete(before.clone()));
+                }
+            }
+
+            match (raw_segments.get(idx + 1), raw_segments.get(idx + 2)) {
+                (Some(after), Some(next))
+                    if after.is_type(SyntaxKind::Whitespace)
+                        && !ends_spacing(next)
+                        && after.raw() != " " =>
+                {
+                    fixes.push(LintFix::replace(
+                        after.clone(),
+                        vec![SegmentBuilder::whitespace(context.tables.next_id(), " ")],
+                        None,
+                    ));
+                }
+                (Some(next), _) if next.is_code() && !ends_spacing(next) && !self.allow_compact => {
+                    fixes.push(LintFix::create_after(
+                        comma.clone(),
+                        vec![SegmentBuilder::whitespace(context.tables.next_id(), " ")],
+                        None,
+                    ));
+                }
+                _ => {}
+            }
+
+            if !fixes.is_empty() {
+                results.push(LintResult::new(
+                    Some(comma.clone()),
+                    fixes,
+                    Some("Expected a single space after the comma and none before it.".into()),
+                    None,
+                ));
+            }
+        }
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
+
+/// Segments after which a comma should not be followed by a space, either
+/// because the line or file ends, or because a bracket closes.
+fn ends_spacing(segment: &ErasedSegment) -> bool {
+    segment.is_comment()
+        || matches!(
+            segment.get_type(),
+            SyntaxKind::Newline
+                | SyntaxKind::EndOfFile
+                | SyntaxKind::EndBracket
+                | SyntaxKind::EndSquareBracket
+        )
+}
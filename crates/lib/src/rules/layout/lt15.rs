@@ -0,0 +1,203 @@
+use ahash::AHashMap;
+use itertools::Itertools;
+use sqruff_lib_core::dialects::syntax::SyntaxKind;
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::SegmentBuilder;
+use strum_macros::{AsRefStr, EnumString};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+#[derive(Debug, Copy, Clone, AsRefStr, EnumString, PartialEq, Default)]
+#[strum(serialize_all = "snake_case")]
+enum CastOperatorSpacing {
+    #[default]
+    Tight,
+    Spaced,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleLT15 {
+    force_enable: bool,
+    cast_operator_spacing: CastOperatorSpacing,
+}
+
+impl Rule for RuleLT15 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleLT15 {
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+            cast_operator_spacing: config["cast_operator_spacing"]
+                .as_string()
+                .unwrap()
+                .parse()
+                .map_err(|_| "cast_operator_spacing must be either tight or spaced".to_string())?,
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "layout.cast_spacing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Consistent spacing around the :: cast operator."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled by default and can be enabled with the `force_enable`
+config, as LT01 already applies the spacing configured for `casting_operator`
+in the layout config.
+
+Whitespace at the start or end of a line is left alone. Set
+`cast_operator_spacing` to `spaced` to require a single space on either side of
+the operator instead, along with `spacing_before = single` and
+`spacing_after = single` in the `casting_operator` layout configuration so that
+`layout.spacing` agrees.
+
+**Anti-pattern**
+
+```sql
+SELECT
+    amount :: int,
+    created_at:: date
+FROM orders
+```
+
+**Best practice**
+
+```sql
+SELECT
+    amount::int,
+    created_at::date
+FROM orders
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Layout]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let raw_segments = context
+            .segment
+            .get_raw_segments()
+            .into_iter()
+            .filter(|it| !it.is_meta())
+            .collect_vec();
+
+        let mut results = Vec::new();
+        for (idx, operator) in raw_segments.iter().enumerate() {
+            if !operator.is_type(SyntaxKind::CastingOperator)
+                || operator.raw() != "::"
+                || operator.is_templated()
+            {
+                continue;
+            }
+
+            let before = idx.checked_sub(1).map(|it| &raw_segments[it]);
+            let after = raw_segments.get(idx + 1);
+            let mut fixes = Vec::new();
+
+            match self.cast_operator_spacing {
+                CastOperatorSpacing::Tight => {
+                    if let Some(before) = before.filter(|it| it.is_type(SyntaxKind::Whitespace)) {
+                        let starts_line =
+                            idx < 2 || raw_segments[idx - 2].is_type(SyntaxKind::Newline);
+                        if !starts_line {
+                            fixes.push(LintFix::delete(before.clone()));
+                        }
+                    }
+                    if let Some(after) = after.filter(|it| it.is_type(SyntaxKind::Whitespace)) {
+                        let ends_line = raw_segments
+                            .get(idx + 2)
+                            .is_none_or(|it| it.is_type(SyntaxKind::Newline) || it.is_comment());
+                        if !ends_line {
+                            fixes.push(LintFix::delete(after.clone()));
+                        }
+                    }
+                }
+                CastOperatorSpacing::Spaced => {
+                    match before {
+                        Some(before)
+                            if before.is_type(SyntaxKind::Whitespace) && before.raw() != " " =>
+                        {
+                            let starts_line =
+                                idx < 2 || raw_segments[idx - 2].is_type(SyntaxKind::Newline);
+                            if !starts_line {
+                                fixes.push(LintFix::replace(
+                                    before.clone(),
+                                    vec![SegmentBuilder::whitespace(context.tables.next_id(), " ")],
+                                    None,
+                                ));
+                            }
+                        }
+                        Some(before) if before.is_code() => {
+                            fixes.push(LintFix::create_before(
+                                operator.clone(),
+                                vec![SegmentBuilder::whitespace(context.tables.next_id(), " ")],
+                            ));
+                        }
+                        _ => {}
+                    }
+                    match after {
+                        Some(after)
+                            if after.is_type(SyntaxKind::Whitespace) && after.raw() != " " =>
+                        {
+                            let ends_line = raw_segments.get(idx + 2).is_none_or(|it| {
+                                it.is_type(SyntaxKind::Newline) || it.is_comment()
+                            });
+                            if !ends_line {
+                                fixes.push(LintFix::replace(
+                                    after.clone(),
+                                    vec![SegmentBuilder::whitespace(context.tables.next_id(), " ")],
+                                    None,
+                                ));
+                            }
+                        }
+                        Some(after) if after.is_code() => {
+                            fixes.push(LintFix::create_after(
+                                operator.clone(),
+                                vec![SegmentBuilder::whitespace(context.tables.next_id(), " ")],
+                                None,
+                            ));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            if !fixes.is_empty() {
+                let message = match self.cast_operator_spacing {
+                    CastOperatorSpacing::Tight => "Expected no whitespace around the :: operator.",
+                    CastOperatorSpacing::Spaced => {
+                        "Expected a single space on either side of the :: operator."
+                    }
+                };
+                results.push(LintResult::new(
+                    Some(operator.clone()),
+                    fixes,
+                    Some(message.into()),
+                    None,
+                ));
+            }
+        }
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
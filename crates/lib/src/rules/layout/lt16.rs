@@ -0,0 +1,133 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::SegmentBuilder;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleLT16 {
+    force_enable: bool,
+}
+
+impl Rule for RuleLT16 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleLT16 {
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "layout.keyword_spacing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Keywords of a multi-word clause should be separated by a single space."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled by default and can be enabled with the `force_enable`
+config. LT01 already collapses runs of whitespace between keywords, so this
+is only useful when LT01 is excluded.
+
+**Anti-pattern**
+
+The keywords making up `ORDER BY`, `GROUP BY`, `PARTITION BY`, joins and set
+operators are separated by more than one space, usually left behind by
+hand-formatting.
+
+```sql
+SELECT a, count(*)
+FROM foo
+LEFT  OUTER JOIN bar USING (a)
+GROUP   BY a
+ORDER BY  a
+```
+
+**Best practice**
+
+Separate the keywords with a single space. Keywords split across lines are
+left alone.
+
+```sql
+SELECT a, count(*)
+FROM foo
+LEFT OUTER JOIN bar USING (a)
+GROUP BY a
+ORDER BY a
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Layout]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let children = context.segment.segments();
+        let mut results = Vec::new();
+
+        for window in children.windows(3) {
+            let [before, whitespace, after] = window else {
+                unreachable!()
+            };
+
+            if !before.is_type(SyntaxKind::Keyword)
+                || !after.is_type(SyntaxKind::Keyword)
+                || !whitespace.is_type(SyntaxKind::Whitespace)
+                || whitespace.raw() == " "
+                || whitespace.is_templated()
+            {
+                continue;
+            }
+
+            results.push(LintResult::new(
+                Some(whitespace.clone()),
+                vec![LintFix::replace(
+                    whitespace.clone(),
+                    vec![SegmentBuilder::whitespace(context.tables.next_id(), " ")],
+                    None,
+                )],
+                Some(format!(
+                    "Expected a single space between `{}` and `{}`.",
+                    before.raw(),
+                    after.raw()
+                )),
+                None,
+            ));
+        }
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(
+            const {
+                SyntaxSet::new(&[
+                    SyntaxKind::OrderbyClause,
+                    SyntaxKind::GroupbyClause,
+                    SyntaxKind::PartitionbyClause,
+                    SyntaxKind::JoinClause,
+                    SyntaxKind::SetOperator,
+                    SyntaxKind::ClusterByClause,
+                    SyntaxKind::DistributeByClause,
+                    SyntaxKind::SortByClause,
+                ])
+            },
+        )
+        .into()
+    }
+}
@@ -6,6 +6,10 @@ pub mod rf03;
 pub mod rf04;
 pub mod rf05;
 pub mod rf06;
+pub mod rf07;
+pub mod rf08;
+pub mod rf09;
+pub mod rf10;
 
 pub fn rules() -> Vec<ErasedRule> {
     use crate::core::rules::base::Erased as _;
@@ -17,5 +21,9 @@ pub fn rules() -> Vec<ErasedRule> {
         rf04::RuleRF04::default().erased(),
         rf05::RuleRF05::default().erased(),
         rf06::RuleRF06::default().erased(),
+        rf07::RuleRF07::default().erased(),
+        rf08::RuleRF08.erased(),
+        rf09::RuleRF09::default().erased(),
+        rf10::RuleRF10::default().erased(),
     ]
 }
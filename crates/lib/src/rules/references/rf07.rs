@@ -0,0 +1,179 @@
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+/// Words which are reserved by recent revisions of the SQL standard
+/// (SQL:2011 through SQL:2023) but are not yet reserved by most engines.
+const FUTURE_RESERVED_KEYWORDS: &[&str] = &[
+    "ANY_VALUE",
+    "BTRIM",
+    "CLASSIFIER",
+    "CONTAINS",
+    "DECFLOAT",
+    "DEFINE",
+    "EMPTY",
+    "EQUALS",
+    "FRAME_ROW",
+    "GREATEST",
+    "GROUPS",
+    "INITIAL",
+    "JSON",
+    "JSON_ARRAY",
+    "JSON_ARRAYAGG",
+    "JSON_EXISTS",
+    "JSON_OBJECT",
+    "JSON_OBJECTAGG",
+    "JSON_QUERY",
+    "JSON_SCALAR",
+    "JSON_SERIALIZE",
+    "JSON_TABLE",
+    "JSON_TABLE_PRIMITIVE",
+    "JSON_VALUE",
+    "LEAST",
+    "LIKE_REGEX",
+    "LISTAGG",
+    "LPAD",
+    "LTRIM",
+    "MATCHES",
+    "MATCH_NUMBER",
+    "MATCH_RECOGNIZE",
+    "MEASURES",
+    "OCCURRENCES_REGEX",
+    "OMIT",
+    "ONE",
+    "PAST",
+    "PATTERN",
+    "PER",
+    "PERIOD",
+    "PERMUTE",
+    "PORTION",
+    "POSITION_REGEX",
+    "PRECEDES",
+    "PTF",
+    "RPAD",
+    "RTRIM",
+    "RUNNING",
+    "SEEK",
+    "SUBSET",
+    "SUBSTRING_REGEX",
+    "SUCCEEDS",
+    "SYSTEM_TIME",
+    "TRANSLATE_REGEX",
+    "VERSIONING",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleRF07 {
+    force_enable: bool,
+    future_reserved_words: AHashSet<String>,
+    ignore_words: AHashSet<String>,
+}
+
+impl Rule for RuleRF07 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let future_reserved_words = match config["future_reserved_words"].as_array() {
+            Some(words) => words
+                .iter()
+                .filter_map(|it| it.as_string())
+                .map(|it| it.trim().to_uppercase())
+                .collect(),
+            None => FUTURE_RESERVED_KEYWORDS
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        };
+        let ignore_words = config["ignore_words"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|it| it.trim().to_uppercase())
+            .collect();
+
+        Ok(RuleRF07 {
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+            future_reserved_words,
+            ignore_words,
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "references.future_keywords"
+    }
+
+    fn description(&self) -> &'static str {
+        "Words reserved by newer SQL standards should not be used as identifiers."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled by default and can be enabled with the `force_enable`
+config, as the words it flags are only a guess at what engines will reserve
+next, not what the dialect reserves today.
+
+Some words are not reserved by today's engines but are reserved by recent
+revisions of the SQL standard, and so are likely to become reserved after an
+upgrade. The list of words can be replaced with the `future_reserved_words`
+config.
+
+**Anti-pattern**
+
+In this example, `period` is used as a column name.
+
+```sql
+SELECT
+    period,
+    amount
+FROM payments
+```
+
+**Best practice**
+
+Pick a name which is not reserved by the standard.
+
+```sql
+SELECT
+    billing_period,
+    amount
+FROM payments
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::References]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let raw_upper = context.segment.raw().to_uppercase();
+
+        if self.ignore_words.contains(&raw_upper)
+            || !self.future_reserved_words.contains(&raw_upper)
+        {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            Some(context.segment.clone()),
+            Vec::new(),
+            Some(format!(
+                "'{}' is reserved in newer SQL standards and should not be used as an identifier.",
+                context.segment.raw()
+            )),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::NakedIdentifier]) }).into()
+    }
+}
@@ -0,0 +1,165 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::utils::identifers::identifiers_match;
+
+const IDENTIFIERS: SyntaxSet = SyntaxSet::new(&[
+    SyntaxKind::NakedIdentifier,
+    SyntaxKind::QuotedIdentifier,
+    SyntaxKind::Identifier,
+]);
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleRF08;
+
+impl Rule for RuleRF08 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleRF08.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "references.windows"
+    }
+
+    fn description(&self) -> &'static str {
+        "References to named windows should be defined in the WINDOW clause."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A window function or a named window refers to a window which is not defined
+in the `WINDOW` clause of the query. A named window may only extend a window
+defined before it.
+
+```sql
+SELECT
+    SUM(amount) OVER w2
+FROM payments
+WINDOW
+    w2 AS (w1 ORDER BY created_at),
+    w1 AS (PARTITION BY customer_id)
+```
+
+**Best practice**
+
+Define base windows before the windows which extend them.
+
+```sql
+SELECT
+    SUM(amount) OVER w2
+FROM payments
+WINDOW
+    w1 AS (PARTITION BY customer_id),
+    w2 AS (w1 ORDER BY created_at)
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::References]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let dialect = context.dialect.name;
+        let mut defined: Vec<ErasedSegment> = Vec::new();
+        let mut results = Vec::new();
+
+        let mut check = |reference: ErasedSegment, defined: &[ErasedSegment]| {
+            if !defined
+                .iter()
+                .any(|name| identifiers_match(dialect, name, &reference))
+            {
+                results.push(LintResult::new(
+                    Some(reference.clone()),
+                    Vec::new(),
+                    Some(format!("Window `{}` is not defined.", reference.raw())),
+                    None,
+                ));
+            }
+        };
+
+        let named_windows = context
+            .segment
+            .child(const { &SyntaxSet::new(&[SyntaxKind::NamedWindow]) })
+            .map(|it| {
+                it.children(const { &SyntaxSet::new(&[SyntaxKind::NamedWindowExpression]) })
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        for named_window in &named_windows {
+            let code: Vec<_> = named_window
+                .segments()
+                .iter()
+                .filter(|it| it.is_code())
+                .collect();
+
+            let Some(name) = code
+                .first()
+                .filter(|it| IDENTIFIERS.contains(it.get_type()))
+            else {
+                continue;
+            };
+
+            if let Some(reference) = code.last().and_then(|it| window_reference(it)) {
+                check(reference, &defined);
+            }
+
+            defined.push((*name).clone());
+        }
+
+        for over_clause in context.segment.recursive_crawl(
+            const { &SyntaxSet::new(&[SyntaxKind::OverClause]) },
+            true,
+            const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) },
+            false,
+        ) {
+            if let Some(reference) = over_clause
+                .segments()
+                .iter()
+                .filter(|it| it.is_code())
+                .nth(1)
+                .and_then(window_reference)
+            {
+                check(reference, &defined);
+            }
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectStatement]) }).into()
+    }
+}
+
+/// The window referred to by either a bare window name, or the base window at
+/// the start of a bracketed window specification.
+fn window_reference(segment: &ErasedSegment) -> Option<ErasedSegment> {
+    if IDENTIFIERS.contains(segment.get_type()) {
+        return Some(segment.clone());
+    }
+
+    let specification = if segment.is_type(SyntaxKind::WindowSpecification) {
+        segment.clone()
+    } else if segment.is_type(SyntaxKind::Bracketed) {
+        segment.child(const { &SyntaxSet::new(&[SyntaxKind::WindowSpecification]) })?
+    } else {
+        return None;
+    };
+
+    specification
+        .segments()
+        .iter()
+        .find(|it| it.is_code())
+        .filter(|it| IDENTIFIERS.contains(it.get_type()))
+        .cloned()
+}
@@ -0,0 +1,162 @@
+use std::str::FromStr;
+
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleRF09 {
+    ignore_dialects: Vec<DialectKind>,
+}
+
+impl Rule for RuleRF09 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let ignore_dialects = config["ignore_dialects"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|it| {
+                DialectKind::from_str(it.trim())
+                    .map_err(|_| format!("Unknown dialect in ignore_dialects: {it}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(RuleRF09 { ignore_dialects }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "references.select_without_from"
+    }
+
+    fn description(&self) -> &'static str {
+        "Columns should not be referenced in a SELECT without a FROM clause."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A `SELECT` without a `FROM` clause references a column. There is no table to
+resolve the column against, so this is usually a mistake such as a missing or
+misplaced `FROM` clause.
+
+```sql
+SELECT
+    customer_id,
+    1 AS flag
+```
+
+**Best practice**
+
+Add the `FROM` clause, or only select constants and functions.
+
+```sql
+SELECT
+    customer_id,
+    1 AS flag
+FROM orders
+```
+
+Dialects which resolve such queries against an implicit table can be listed
+in `ignore_dialects`.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::References]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if self.ignore_dialects.contains(&context.dialect.name) {
+            return Vec::new();
+        }
+
+        let select = &context.segment;
+        if select
+            .child(const { &SyntaxSet::new(&[SyntaxKind::FromClause]) })
+            .is_some()
+        {
+            return Vec::new();
+        }
+
+        // Subqueries without a FROM clause may reference outer columns.
+        if context.parent_stack.iter().any(|parent| {
+            matches!(
+                parent.get_type(),
+                SyntaxKind::SelectStatement
+                    | SyntaxKind::Expression
+                    | SyntaxKind::UpdateStatement
+                    | SyntaxKind::DeleteStatement
+                    | SyntaxKind::MergeStatement
+            )
+        }) {
+            return Vec::new();
+        }
+
+        let Some(select_clause) =
+            select.child(const { &SyntaxSet::new(&[SyntaxKind::SelectClause]) })
+        else {
+            return Vec::new();
+        };
+
+        // Lambda parameters are parsed as column references.
+        if select_clause
+            .get_raw_segments()
+            .iter()
+            .any(|it| it.raw() == "->")
+        {
+            return Vec::new();
+        }
+
+        // Some dialects allow later select targets to refer to earlier aliases.
+        let aliases: AHashSet<String> = select_clause
+            .recursive_crawl(
+                const { &SyntaxSet::new(&[SyntaxKind::AliasExpression]) },
+                true,
+                const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) },
+                false,
+            )
+            .iter()
+            .filter_map(|alias| {
+                alias
+                    .segments()
+                    .iter()
+                    .rev()
+                    .find(|it| it.is_code())
+                    .map(|it| it.raw().to_uppercase())
+            })
+            .collect();
+
+        select_clause
+            .recursive_crawl(
+                const { &SyntaxSet::new(&[SyntaxKind::ColumnReference]) },
+                true,
+                const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) },
+                false,
+            )
+            .into_iter()
+            .filter(|column| !aliases.contains(&column.raw().to_uppercase()))
+            .map(|column| {
+                LintResult::new(
+                    Some(column.clone()),
+                    Vec::new(),
+                    Some(format!(
+                        "Column `{}` is referenced in a SELECT without a FROM clause.",
+                        column.raw()
+                    )),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectStatement]) }).into()
+    }
+}
@@ -0,0 +1,179 @@
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::init::DialectKind;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::from::FromExpressionElementSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleRF10 {
+    force_enable: bool,
+}
+
+impl Rule for RuleRF10 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleRF10 {
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "references.using_columns"
+    }
+
+    fn description(&self) -> &'static str {
+        "Columns merged by a USING join should not be qualified."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A join with `USING` merges the listed columns into one, but a later
+reference still qualifies one with a table. Some engines reject this, and in
+others it is unclear which side of an outer join the value comes from.
+
+```sql
+SELECT a.id, b.name
+FROM a
+LEFT JOIN b USING (id)
+```
+
+**Best practice**
+
+Refer to the merged column without a qualifier.
+
+```sql
+SELECT id, b.name
+FROM a
+LEFT JOIN b USING (id)
+```
+
+This rule is only enabled by default for dialects which reject qualified
+references to `USING` columns (e.g. trino), and can be enabled for others with
+the `force_enable` configuration option.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::References]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !(self.force_enable
+            || matches!(
+                context.dialect.name,
+                DialectKind::Trino | DialectKind::Athena
+            ))
+        {
+            return Vec::new();
+        }
+
+        let Some(from_clause) = context
+            .segment
+            .child(const { &SyntaxSet::new(&[SyntaxKind::FromClause]) })
+        else {
+            return Vec::new();
+        };
+
+        // Pairs of upper-cased table aliases and the USING columns they share.
+        let mut merged = AHashSet::new();
+        for from_expression in
+            from_clause.children(const { &SyntaxSet::new(&[SyntaxKind::FromExpression]) })
+        {
+            let mut aliases = Vec::new();
+            for child in from_expression.segments() {
+                let element = match child.get_type() {
+                    SyntaxKind::FromExpressionElement => child.clone(),
+                    SyntaxKind::JoinClause => {
+                        match child
+                            .child(const { &SyntaxSet::new(&[SyntaxKind::FromExpressionElement]) })
+                        {
+                            Some(element) => element,
+                            None => continue,
+                        }
+                    }
+                    _ => continue,
+                };
+                aliases.push(
+                    FromExpressionElementSegment(element)
+                        .eventual_alias()
+                        .ref_str
+                        .to_uppercase(),
+                );
+
+                if !child.is_type(SyntaxKind::JoinClause) {
+                    continue;
+                }
+                let Some(using_idx) = child
+                    .segments()
+                    .iter()
+                    .position(|it| it.is_keyword("USING"))
+                else {
+                    continue;
+                };
+                let Some(columns) = child.segments()[using_idx..]
+                    .iter()
+                    .find(|it| it.is_type(SyntaxKind::Bracketed))
+                else {
+                    continue;
+                };
+
+                for column in columns.segments() {
+                    if !matches!(
+                        column.get_type(),
+                        SyntaxKind::Identifier | SyntaxKind::NakedIdentifier
+                    ) {
+                        continue;
+                    }
+                    for alias in &aliases {
+                        merged.insert((alias.clone(), column.raw().to_uppercase()));
+                    }
+                }
+            }
+        }
+
+        if merged.is_empty() {
+            return Vec::new();
+        }
+
+        context
+            .segment
+            .recursive_crawl(
+                const { &SyntaxSet::new(&[SyntaxKind::ColumnReference]) },
+                true,
+                const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) },
+                false,
+            )
+            .into_iter()
+            .filter_map(|reference| {
+                let parts = reference.reference().iter_raw_references();
+                let [.., qualifier, column] = parts.as_slice() else {
+                    return None;
+                };
+                if !merged.contains(&(qualifier.part.to_uppercase(), column.part.to_uppercase())) {
+                    return None;
+                }
+
+                Some(LintResult::new(
+                    Some(reference.clone()),
+                    Vec::new(),
+                    Some(format!(
+                        "Column `{}` is merged by a USING join, refer to it as `{}` instead.",
+                        reference.raw(),
+                        column.part
+                    )),
+                    None,
+                ))
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectStatement]) }).into()
+    }
+}
@@ -9,6 +9,14 @@ pub mod st06;
 pub mod st07;
 pub mod st08;
 pub mod st09;
+pub mod st10;
+pub mod st11;
+pub mod st12;
+pub mod st13;
+pub mod st14;
+pub mod st15;
+pub mod st16;
+pub mod st17;
 
 pub fn rules() -> Vec<ErasedRule> {
     use crate::core::rules::base::Erased as _;
@@ -23,5 +31,13 @@ pub fn rules() -> Vec<ErasedRule> {
         st07::RuleST07.erased(),
         st08::RuleST08.erased(),
         st09::RuleST09::default().erased(),
+        st10::RuleST10.erased(),
+        st11::RuleST11::default().erased(),
+        st12::RuleST12::default().erased(),
+        st13::RuleST13.erased(),
+        st14::RuleST14::default().erased(),
+        st15::RuleST15::default().erased(),
+        st16::RuleST16::default().erased(),
+        st17::RuleST17.erased(),
     ]
 }
@@ -81,7 +81,9 @@ from x
         if context.parent_stack.len() >= 2
             && matches!(
                 context.parent_stack[context.parent_stack.len() - 2].get_type(),
-                SyntaxKind::InsertStatement | SyntaxKind::SetExpression
+                SyntaxKind::InsertStatement
+                    | SyntaxKind::ReplaceStatement
+                    | SyntaxKind::SetExpression
             )
         {
             return Vec::new();
@@ -90,7 +92,9 @@ from x
         if context.parent_stack.len() >= 3
             && matches!(
                 context.parent_stack[context.parent_stack.len() - 3].get_type(),
-                SyntaxKind::InsertStatement | SyntaxKind::SetExpression
+                SyntaxKind::InsertStatement
+                    | SyntaxKind::ReplaceStatement
+                    | SyntaxKind::SetExpression
             )
             && context.parent_stack[context.parent_stack.len() - 2].get_type()
                 == SyntaxKind::WithCompoundStatement
@@ -0,0 +1,262 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+/// The type of an expression, where it is obvious without type inference.
+enum KnownType {
+    Integer,
+    Boolean,
+    Named(String),
+}
+
+impl KnownType {
+    fn of(segment: &ErasedSegment) -> Option<KnownType> {
+        let segment = unwrap_expression(segment);
+        match segment.get_type() {
+            SyntaxKind::NumericLiteral if segment.raw().bytes().all(|b| b.is_ascii_digit()) => {
+                Some(KnownType::Integer)
+            }
+            SyntaxKind::BooleanLiteral => Some(KnownType::Boolean),
+            SyntaxKind::Function => {
+                let (_, data_type) = cast_function_arguments(&segment)?;
+                Some(KnownType::Named(normalise_type(&data_type)))
+            }
+            SyntaxKind::CastExpression => {
+                let code = code_children(&segment);
+                if code.len() < 3 || !code[code.len() - 1].is_type(SyntaxKind::DataType) {
+                    return None;
+                }
+                Some(KnownType::Named(normalise_type(&code[code.len() - 1])))
+            }
+            _ => None,
+        }
+    }
+
+    fn matches(&self, data_type: &ErasedSegment) -> bool {
+        let data_type = normalise_type(data_type);
+        match self {
+            KnownType::Integer => matches!(data_type.as_str(), "INT" | "INTEGER" | "INT64"),
+            KnownType::Boolean => matches!(data_type.as_str(), "BOOL" | "BOOLEAN"),
+            KnownType::Named(name) => *name == data_type,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST10;
+
+impl Rule for RuleST10 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST10.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.redundant_cast"
+    }
+
+    fn description(&self) -> &'static str {
+        "Unnecessary cast to the type a value already has."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A value is cast to the type it already has, such as a literal cast to its own
+type or a value cast twice to the same type.
+
+```sql
+SELECT
+    CAST(CAST(amount AS int) AS int) AS amount,
+    1::int AS flag
+FROM orders
+```
+
+**Best practice**
+
+Remove the redundant cast.
+
+```sql
+SELECT
+    CAST(amount AS int) AS amount,
+    1 AS flag
+FROM orders
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if context.segment.is_type(SyntaxKind::Function) {
+            eval_cast_function(&context.segment)
+        } else {
+            eval_shorthand_cast(&context.segment)
+        }
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(
+            const { SyntaxSet::new(&[SyntaxKind::Function, SyntaxKind::CastExpression]) },
+        )
+        .into()
+    }
+}
+
+fn eval_cast_function(function: &ErasedSegment) -> Vec<LintResult> {
+    let Some((value, data_type)) = cast_function_arguments(function) else {
+        return Vec::new();
+    };
+
+    if !KnownType::of(&value).is_some_and(|it| it.matches(&data_type)) {
+        return Vec::new();
+    }
+
+    let Some(function_name) =
+        function.child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+    else {
+        return Vec::new();
+    };
+
+    vec![LintResult::new(
+        Some(function_name),
+        vec![LintFix::replace(
+            function.clone(),
+            vec![unwrap_expression(&value)],
+            None,
+        )],
+        Some(format!(
+            "Redundant cast to `{}`.",
+            normalise_type(&data_type)
+        )),
+        None,
+    )]
+}
+
+fn eval_shorthand_cast(cast: &ErasedSegment) -> Vec<LintResult> {
+    let children = cast.segments();
+    let Some(base_idx) = children.iter().position(|it| it.is_code()) else {
+        return Vec::new();
+    };
+
+    let mut known_type = KnownType::of(&children[base_idx]);
+    let mut previous_idx = base_idx;
+    let mut results = Vec::new();
+
+    let mut idx = base_idx + 1;
+    while idx < children.len() {
+        let segment = &children[idx];
+        if !segment.is_code() {
+            idx += 1;
+            continue;
+        }
+
+        // Anything other than `:: data_type`, such as a time zone, stops the
+        // chain as its type is no longer obvious.
+        if !segment.is_type(SyntaxKind::CastingOperator) {
+            break;
+        }
+        let Some(type_idx) = (idx + 1..children.len()).find(|&it| children[it].is_code()) else {
+            break;
+        };
+        let data_type = &children[type_idx];
+        if !data_type.is_type(SyntaxKind::DataType) {
+            break;
+        }
+
+        if known_type.as_ref().is_some_and(|it| it.matches(data_type)) {
+            let removed = &children[previous_idx + 1..=type_idx];
+            let fixes = if removed.iter().any(|it| it.is_comment()) {
+                Vec::new()
+            } else {
+                removed.iter().cloned().map(LintFix::delete).collect()
+            };
+
+            results.push(LintResult::new(
+                Some(segment.clone()),
+                fixes,
+                Some(format!(
+                    "Redundant cast to `{}`.",
+                    normalise_type(data_type)
+                )),
+                None,
+            ));
+        }
+
+        known_type = Some(KnownType::Named(normalise_type(data_type)));
+        previous_idx = type_idx;
+        idx = type_idx + 1;
+    }
+
+    results
+}
+
+/// The value and target type of a `CAST(value AS data_type)` call.
+fn cast_function_arguments(function: &ErasedSegment) -> Option<(ErasedSegment, ErasedSegment)> {
+    let function_name = function.child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })?;
+    if !function_name.raw().eq_ignore_ascii_case("CAST") {
+        return None;
+    }
+
+    let arguments = function.child(const { &SyntaxSet::new(&[SyntaxKind::Bracketed]) })?;
+    let code: Vec<_> = code_children(&arguments)
+        .into_iter()
+        .filter(|it| {
+            !matches!(
+                it.get_type(),
+                SyntaxKind::StartBracket | SyntaxKind::EndBracket
+            )
+        })
+        .collect();
+
+    match code.as_slice() {
+        [value, keyword, data_type]
+            if keyword.is_keyword("AS") && data_type.is_type(SyntaxKind::DataType) =>
+        {
+            Some((value.clone(), data_type.clone()))
+        }
+        _ => None,
+    }
+}
+
+fn code_children(segment: &ErasedSegment) -> Vec<ErasedSegment> {
+    segment
+        .segments()
+        .iter()
+        .filter(|it| it.is_code())
+        .cloned()
+        .collect()
+}
+
+/// Look through expressions wrapping a single segment.
+fn unwrap_expression(segment: &ErasedSegment) -> ErasedSegment {
+    let mut segment = segment.clone();
+    while segment.is_type(SyntaxKind::Expression) {
+        match code_children(&segment).as_slice() {
+            [inner] => segment = inner.clone(),
+            _ => break,
+        }
+    }
+    segment
+}
+
+fn normalise_type(data_type: &ErasedSegment) -> String {
+    data_type
+        .get_raw_segments()
+        .iter()
+        .filter(|it| it.is_code())
+        .map(|it| it.raw().to_uppercase())
+        .collect()
+}
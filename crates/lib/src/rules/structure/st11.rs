@@ -0,0 +1,255 @@
+use ahash::AHashMap;
+use smol_str::StrExt;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::{ErasedSegment, SegmentBuilder};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Clone)]
+pub struct RuleST11 {
+    max_complexity: usize,
+    experimental_fix: bool,
+    force_enable: bool,
+}
+
+impl Default for RuleST11 {
+    fn default() -> Self {
+        RuleST11 {
+            max_complexity: 1,
+            experimental_fix: false,
+            force_enable: false,
+        }
+    }
+}
+
+impl Rule for RuleST11 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST11 {
+            max_complexity: config["max_complexity"].as_int().unwrap_or(1).max(1) as usize,
+            experimental_fix: config["experimental_fix"].as_bool().unwrap_or_default(),
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.inline_cte"
+    }
+
+    fn description(&self) -> &'static str {
+        "Simple CTEs used only once could be inlined."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A CTE with a trivial body is referenced only once, so the reader has to look
+away from the main query to follow it.
+
+```sql
+WITH active_users AS (
+    SELECT * FROM users WHERE is_active
+)
+
+SELECT id FROM active_users
+```
+
+**Best practice**
+
+Inline the CTE as a subquery.
+
+```sql
+SELECT id FROM (
+    SELECT * FROM users WHERE is_active
+) AS active_users
+```
+
+The complexity of a CTE body is the number of `SELECT` statements, set
+operations and joins it contains. Only CTEs with a complexity up to
+`max_complexity` are flagged.
+
+This rule is disabled by default and can be enabled with the `force_enable`
+configuration option. Setting `experimental_fix` also inlines the CTE.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let with = &context.segment;
+        if with.segments().iter().any(|it| it.is_keyword("RECURSIVE")) {
+            return Vec::new();
+        }
+
+        let children = with.segments();
+        let ctes: Vec<usize> = children
+            .iter()
+            .enumerate()
+            .filter(|(_, it)| it.is_type(SyntaxKind::CommonTableExpression))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let references = with.recursive_crawl(
+            const { &SyntaxSet::new(&[SyntaxKind::TableReference]) },
+            true,
+            const { &SyntaxSet::single(SyntaxKind::WithCompoundStatement) },
+            true,
+        );
+
+        let mut results = Vec::new();
+        for (position, &cte_idx) in ctes.iter().enumerate() {
+            let cte = &children[cte_idx];
+            let code: Vec<_> = cte.segments().iter().filter(|it| it.is_code()).collect();
+            let (Some(name), Some(body)) = (code.first(), code.last()) else {
+                continue;
+            };
+            if !body.is_type(SyntaxKind::Bracketed) || complexity(body) > self.max_complexity {
+                continue;
+            }
+
+            let name_key = name.raw().to_uppercase_smolstr();
+            let used: Vec<_> = references
+                .iter()
+                .filter(|it| it.raw().to_uppercase_smolstr() == name_key)
+                .collect();
+            let [reference] = used.as_slice() else {
+                continue;
+            };
+
+            let fixes = if self.experimental_fix && code.len() == 3 {
+                inline_fixes(context, &ctes, position, name, body, reference)
+            } else {
+                Vec::new()
+            };
+
+            results.push(LintResult::new(
+                Some((*name).clone()),
+                fixes,
+                Some(format!(
+                    "CTE \"{}\" is only used once and could be inlined.",
+                    name.raw()
+                )),
+                None,
+            ));
+        }
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::WithCompoundStatement]) })
+            .into()
+    }
+}
+
+fn complexity(body: &ErasedSegment) -> usize {
+    body.recursive_crawl(
+        const {
+            &SyntaxSet::new(&[
+                SyntaxKind::SelectStatement,
+                SyntaxKind::SetOperator,
+                SyntaxKind::JoinClause,
+                SyntaxKind::WithCompoundStatement,
+            ])
+        },
+        true,
+        &SyntaxSet::EMPTY,
+        true,
+    )
+    .len()
+}
+
+/// Replace the reference with the CTE body as a derived table, and remove the
+/// CTE definition. No fixes are returned where comments would be lost.
+fn inline_fixes(
+    context: &RuleContext,
+    ctes: &[usize],
+    position: usize,
+    name: &ErasedSegment,
+    body: &ErasedSegment,
+    reference: &ErasedSegment,
+) -> Vec<LintFix> {
+    let Some(from_expression_element) = context
+        .segment
+        .recursive_crawl(
+            const { &SyntaxSet::new(&[SyntaxKind::FromExpressionElement]) },
+            true,
+            const { &SyntaxSet::single(SyntaxKind::WithCompoundStatement) },
+            true,
+        )
+        .into_iter()
+        .find(|element| {
+            element
+                .child(const { &SyntaxSet::new(&[SyntaxKind::TableExpression]) })
+                .and_then(|it| it.child(const { &SyntaxSet::new(&[SyntaxKind::TableReference]) }))
+                .is_some_and(|it| &it == reference)
+        })
+    else {
+        return Vec::new();
+    };
+
+    let children = context.segment.segments();
+    let removed = if ctes.len() == 1 {
+        // Remove the whole WITH, up to the main query.
+        let Some(query_idx) = children[ctes[0] + 1..]
+            .iter()
+            .position(|it| it.is_code())
+            .map(|it| it + ctes[0] + 1)
+        else {
+            return Vec::new();
+        };
+        &children[..query_idx]
+    } else if position == 0 {
+        &children[ctes[0]..ctes[1]]
+    } else {
+        &children[ctes[position - 1] + 1..=ctes[position]]
+    };
+    if removed.iter().any(|it| it.is_comment()) {
+        return Vec::new();
+    }
+
+    let mut edit = vec![body.deep_clone()];
+    if from_expression_element
+        .child(const { &SyntaxSet::new(&[SyntaxKind::AliasExpression]) })
+        .is_none()
+    {
+        edit.extend([
+            SegmentBuilder::whitespace(context.tables.next_id(), " "),
+            SegmentBuilder::node(
+                context.tables.next_id(),
+                SyntaxKind::AliasExpression,
+                context.dialect.name,
+                vec![
+                    SegmentBuilder::keyword(context.tables.next_id(), "AS"),
+                    SegmentBuilder::whitespace(context.tables.next_id(), " "),
+                    SegmentBuilder::token(
+                        context.tables.next_id(),
+                        name.raw(),
+                        SyntaxKind::NakedIdentifier,
+                    )
+                    .finish(),
+                ],
+            )
+            .finish(),
+        ]);
+    }
+
+    let mut fixes: Vec<LintFix> = removed.iter().cloned().map(LintFix::delete).collect();
+    fixes.push(LintFix::replace(reference.clone(), edit, None));
+    fixes
+}
@@ -0,0 +1,187 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+use strum_macros::{AsRefStr, EnumString};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+#[derive(Debug, Copy, Clone, AsRefStr, EnumString, PartialEq)]
+#[strum(serialize_all = "snake_case")]
+enum StatementCategory {
+    Ddl,
+    Dml,
+    Query,
+    Dcl,
+    Transaction,
+    Other,
+}
+
+impl StatementCategory {
+    fn of(statement: &ErasedSegment) -> StatementCategory {
+        let kind = statement.get_type();
+        let name = kind.as_str();
+
+        if kind == SyntaxKind::WithCompoundStatement {
+            // A CTE is categorised by the statement it prefixes.
+            return statement
+                .segments()
+                .iter()
+                .rev()
+                .find(|it| it.is_code())
+                .map_or(StatementCategory::Query, StatementCategory::of);
+        }
+
+        if matches!(
+            kind,
+            SyntaxKind::SelectStatement
+                | SyntaxKind::SetExpression
+                | SyntaxKind::ValuesClause
+                | SyntaxKind::ExplainStatement
+                | SyntaxKind::DescribeStatement
+                | SyntaxKind::ShowStatement
+        ) {
+            StatementCategory::Query
+        } else if [
+            "create_",
+            "alter_",
+            "drop_",
+            "truncate_",
+            "comment_",
+            "undrop_",
+        ]
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+        {
+            StatementCategory::Ddl
+        } else if ["insert_", "update_", "delete_", "merge_", "copy_"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        {
+            StatementCategory::Dml
+        } else if kind == SyntaxKind::AccessStatement {
+            StatementCategory::Dcl
+        } else if kind == SyntaxKind::TransactionStatement {
+            StatementCategory::Transaction
+        } else {
+            StatementCategory::Other
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST12 {
+    file_categories: Vec<(glob::Pattern, Vec<StatementCategory>)>,
+}
+
+impl Rule for RuleST12 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let file_categories = config["file_statement_categories"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|entry| {
+                let (pattern, categories) = entry.trim().rsplit_once(':').ok_or_else(|| {
+                    format!(
+                        "file_statement_categories entry must be pattern:categories, got {entry}"
+                    )
+                })?;
+                let pattern = glob::Pattern::new(pattern.trim()).map_err(|e| e.to_string())?;
+                let categories = categories
+                    .split('|')
+                    .map(|category| {
+                        category
+                            .trim()
+                            .parse()
+                            .map_err(|_| format!("Unknown statement category: {category}"))
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok((pattern, categories))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(RuleST12 { file_categories }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.statement_category"
+    }
+
+    fn description(&self) -> &'static str {
+        "Statements should belong to the categories allowed for the file."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A file which should only contain one kind of statement, such as a migration
+made of DDL, contains a statement of another kind.
+
+```sql
+CREATE TABLE users (id INT);
+
+SELECT * FROM users;
+```
+
+**Best practice**
+
+Move the statement to a file where it belongs.
+
+```sql
+CREATE TABLE users (id INT);
+```
+
+Files are matched with `file_statement_categories`, a comma separated list of
+`pattern:categories` entries, e.g. `migrations/*.sql:ddl|transaction`. Patterns
+are globs matched against the path as passed to sqruff, and the first matching
+pattern applies. The categories are `ddl`, `dml`, `query`, `dcl`,
+`transaction` and `other`.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        let Some(path) = context.path.as_deref() else {
+            return Vec::new();
+        };
+        let Some((pattern, allowed)) = self
+            .file_categories
+            .iter()
+            .find(|(pattern, _)| pattern.matches(path))
+        else {
+            return Vec::new();
+        };
+
+        context
+            .segment
+            .children(const { &SyntaxSet::new(&[SyntaxKind::Statement]) })
+            .filter_map(|statement| {
+                let inner = statement.segments().iter().find(|it| it.is_code())?;
+                let category = StatementCategory::of(inner);
+                (!allowed.contains(&category)).then(|| {
+                    LintResult::new(
+                        Some(statement.clone()),
+                        Vec::new(),
+                        Some(format!(
+                            "Statement of category `{}` is not allowed in files matching `{}`.",
+                            category.as_ref(),
+                            pattern.as_str()
+                        )),
+                        None,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
@@ -0,0 +1,101 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST13;
+
+impl Rule for RuleST13 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST13.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.check_subquery"
+    }
+
+    fn description(&self) -> &'static str {
+        "CHECK constraints should not contain subqueries."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A `CHECK` constraint uses a subquery. This is forbidden by the SQL standard and
+rejected by most engines.
+
+```sql
+CREATE TABLE orders (
+    customer_id INT CHECK (customer_id IN (SELECT id FROM customers))
+)
+```
+
+**Best practice**
+
+Use a foreign key, or only refer to the columns of the row being checked.
+
+```sql
+CREATE TABLE orders (
+    customer_id INT REFERENCES customers (id)
+)
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !context.segment.raw().eq_ignore_ascii_case("CHECK") {
+            return Vec::new();
+        }
+        let Some(parent) = context.parent_stack.last() else {
+            return Vec::new();
+        };
+
+        let siblings: Vec<_> = parent.segments().iter().filter(|it| it.is_code()).collect();
+        let Some(idx) = siblings.iter().position(|it| *it == &context.segment) else {
+            return Vec::new();
+        };
+
+        // `WITH CHECK` expressions of row level security policies may query
+        // other tables.
+        if idx > 0 && siblings[idx - 1].is_keyword("WITH") {
+            return Vec::new();
+        }
+        let Some(expression) = siblings
+            .get(idx + 1)
+            .filter(|it| it.is_type(SyntaxKind::Bracketed))
+        else {
+            return Vec::new();
+        };
+
+        expression
+            .recursive_crawl(
+                const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) },
+                false,
+                &SyntaxSet::EMPTY,
+                false,
+            )
+            .into_iter()
+            .map(|subquery| {
+                LintResult::new(
+                    Some(subquery),
+                    Vec::new(),
+                    Some("Subquery used in a CHECK constraint.".into()),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Keyword]) }).into()
+    }
+}
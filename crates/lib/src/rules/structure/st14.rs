@@ -0,0 +1,184 @@
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST14 {
+    force_enable: bool,
+}
+
+impl Rule for RuleST14 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST14 {
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.insert_column_order"
+    }
+
+    fn description(&self) -> &'static str {
+        "INSERT column lists should follow the order of the table definition."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A table created in the file is inserted into with its columns listed in a
+different order to its definition, which makes it easy to pair a value with
+the wrong column.
+
+```sql
+CREATE TABLE users (id INT, name VARCHAR(100), email VARCHAR(100));
+
+INSERT INTO users (email, id, name) VALUES ('a@example.com', 1, 'a');
+```
+
+**Best practice**
+
+List the columns in the order they are defined.
+
+```sql
+CREATE TABLE users (id INT, name VARCHAR(100), email VARCHAR(100));
+
+INSERT INTO users (id, name, email) VALUES (1, 'a', 'a@example.com');
+```
+
+Only tables created in the same file are checked, and columns missing from
+the definition are ignored. This rule is disabled by default and can be
+enabled with the `force_enable` configuration option.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let tables: AHashMap<String, Vec<String>> = context
+            .segment
+            .recursive_crawl(
+                const { &SyntaxSet::new(&[SyntaxKind::CreateTableStatement]) },
+                true,
+                &SyntaxSet::EMPTY,
+                false,
+            )
+            .iter()
+            .filter_map(|create| {
+                let table =
+                    create.child(const { &SyntaxSet::new(&[SyntaxKind::TableReference]) })?;
+                let definition =
+                    create.child(const { &SyntaxSet::new(&[SyntaxKind::Bracketed]) })?;
+                let columns: Vec<_> = definition
+                    .segments()
+                    .iter()
+                    .filter_map(|column| match column.get_type() {
+                        SyntaxKind::ColumnDefinition => column
+                            .segments()
+                            .iter()
+                            .find(|it| it.is_code())
+                            .map(|name| normalise(name.raw())),
+                        // Some dialects put the column name directly in the brackets.
+                        SyntaxKind::ColumnReference => Some(normalise(column.raw())),
+                        _ => None,
+                    })
+                    .collect();
+                (!columns.is_empty()).then(|| (normalise(table.raw()), columns))
+            })
+            .collect();
+
+        if tables.is_empty() {
+            return Vec::new();
+        }
+
+        context
+            .segment
+            .recursive_crawl(
+                const {
+                    &SyntaxSet::new(&[SyntaxKind::InsertStatement, SyntaxKind::ReplaceStatement])
+                },
+                true,
+                &SyntaxSet::EMPTY,
+                false,
+            )
+            .iter()
+            .filter_map(|insert| {
+                let table =
+                    insert.child(const { &SyntaxSet::new(&[SyntaxKind::TableReference]) })?;
+                let defined = tables.get(&normalise(table.raw()))?;
+                let column_list = insert_column_list(insert)?;
+
+                let positions: Vec<_> = column_list
+                    .segments()
+                    .iter()
+                    .filter(|it| it.is_type(SyntaxKind::ColumnReference))
+                    .filter_map(|column| {
+                        let name = normalise(column.raw());
+                        defined.iter().position(|it| *it == name)
+                    })
+                    .collect();
+                if positions.is_sorted() {
+                    return None;
+                }
+
+                Some(LintResult::new(
+                    Some(column_list.clone()),
+                    Vec::new(),
+                    Some(format!(
+                        "Columns are inserted in a different order to the definition of `{}`.",
+                        table.raw()
+                    )),
+                    None,
+                ))
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
+
+/// The bracketed column list of an INSERT, if it has one.
+fn insert_column_list(insert: &ErasedSegment) -> Option<ErasedSegment> {
+    insert
+        .children(const { &SyntaxSet::new(&[SyntaxKind::Bracketed]) })
+        .find(|bracketed| {
+            let kinds: AHashSet<_> = bracketed
+                .segments()
+                .iter()
+                .filter(|it| it.is_code())
+                .map(|it| it.get_type())
+                .collect();
+            kinds.contains(&SyntaxKind::ColumnReference)
+                && kinds.iter().all(|kind| {
+                    matches!(
+                        kind,
+                        SyntaxKind::ColumnReference
+                            | SyntaxKind::Comma
+                            | SyntaxKind::StartBracket
+                            | SyntaxKind::EndBracket
+                    )
+                })
+        })
+        .cloned()
+}
+
+fn normalise(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '"' | '`' | '[' | ']'))
+        .collect::<String>()
+        .to_uppercase()
+}
@@ -0,0 +1,163 @@
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST15 {
+    allowed_functions: AHashSet<String>,
+    force_enable: bool,
+}
+
+impl Rule for RuleST15 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let allowed_functions = config["allowed_functions"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|it| it.trim().to_uppercase())
+            .filter(|it| !it.is_empty())
+            .collect();
+
+        Ok(RuleST15 {
+            allowed_functions,
+            force_enable: config["force_enable"].as_bool().unwrap_or_default(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.sargable_predicate"
+    }
+
+    fn description(&self) -> &'static str {
+        "Columns should not be wrapped in functions when compared in a filter."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A column is passed through a function before being compared in a `WHERE` or
+`ON` condition. Most engines can then no longer use an index on the column,
+and have to evaluate the function for every row.
+
+```sql
+SELECT *
+FROM orders
+WHERE DATE(created_at) = '2023-01-01'
+```
+
+**Best practice**
+
+Compare the bare column against a range instead.
+
+```sql
+SELECT *
+FROM orders
+WHERE created_at >= '2023-01-01' AND created_at < '2023-01-02'
+```
+
+Functions covered by an expression index can be listed in
+`allowed_functions`. This rule is disabled by default and can be enabled with
+the `force_enable` configuration option.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if !self.force_enable {
+            return Vec::new();
+        }
+
+        let function = &context.segment;
+        let Some(function_name) =
+            function.child(const { &SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+        else {
+            return Vec::new();
+        };
+        if self
+            .allowed_functions
+            .contains(&function_name.raw().to_uppercase())
+        {
+            return Vec::new();
+        }
+
+        let in_filter = context
+            .parent_stack
+            .iter()
+            .rev()
+            .take_while(|it| !it.is_type(SyntaxKind::SelectStatement))
+            .any(|it| {
+                matches!(
+                    it.get_type(),
+                    SyntaxKind::WhereClause | SyntaxKind::JoinOnCondition
+                )
+            });
+        if !in_filter || !is_compared(context.parent_stack.last(), function) {
+            return Vec::new();
+        }
+
+        let wraps_column = function
+            .child(const { &SyntaxSet::new(&[SyntaxKind::Bracketed]) })
+            .is_some_and(|arguments| arguments.segments().iter().any(is_bare_column));
+        if !wraps_column {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            Some(function.clone()),
+            Vec::new(),
+            Some(format!(
+                "Comparing the result of {}() on a column prevents the use of an index on it.",
+                function_name.raw()
+            )),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Function]) }).into()
+    }
+}
+
+/// Whether the segment is directly next to a comparison operator in its parent.
+fn is_compared(parent: Option<&ErasedSegment>, segment: &ErasedSegment) -> bool {
+    let Some(parent) = parent else {
+        return false;
+    };
+
+    let code: Vec<_> = parent.segments().iter().filter(|it| it.is_code()).collect();
+    let Some(idx) = code.iter().position(|it| *it == segment) else {
+        return false;
+    };
+
+    let is_comparison = |idx: Option<usize>| {
+        idx.and_then(|idx| code.get(idx))
+            .is_some_and(|it| it.is_type(SyntaxKind::ComparisonOperator))
+    };
+    is_comparison(idx.checked_sub(1)) || is_comparison(Some(idx + 1))
+}
+
+fn is_bare_column(argument: &ErasedSegment) -> bool {
+    match argument.get_type() {
+        SyntaxKind::ColumnReference => true,
+        SyntaxKind::Expression => {
+            let code: Vec<_> = argument
+                .segments()
+                .iter()
+                .filter(|it| it.is_code())
+                .collect();
+            matches!(code.as_slice(), [column] if column.is_type(SyntaxKind::ColumnReference))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,174 @@
+use ahash::AHashMap;
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST16 {
+    column_priorities: Vec<glob::Pattern>,
+}
+
+impl Rule for RuleST16 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let column_priorities = config["column_priorities"]
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|it| it.as_string())
+            .map(|it| glob::Pattern::new(&it.trim().to_lowercase()).map_err(|e| e.to_string()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(RuleST16 { column_priorities }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.column_priority"
+    }
+
+    fn description(&self) -> &'static str {
+        "Select targets should follow the configured column priorities."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+This rule is disabled unless `column_priorities` is configured.
+
+**Anti-pattern**
+
+With `column_priorities` set to `id,*_id`, a key column is selected after
+other attributes.
+
+```sql
+SELECT
+    name,
+    id,
+    customer_id
+FROM orders
+```
+
+**Best practice**
+
+Select the columns in the order of the first pattern they match, with columns
+matching no pattern last.
+
+```sql
+SELECT
+    id,
+    customer_id,
+    name
+FROM orders
+```
+
+`column_priorities` is a comma separated list of glob patterns, matched
+against the alias or column name of each select target. Select lists made up
+only of plain column references are reordered when fixing.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        if self.column_priorities.is_empty() {
+            return Vec::new();
+        }
+
+        let elements: Vec<_> = context
+            .segment
+            .children(const { &SyntaxSet::new(&[SyntaxKind::SelectClauseElement]) })
+            .cloned()
+            .collect();
+        let ranks: Vec<_> = elements.iter().map(|it| self.rank(it)).collect();
+
+        let mut results = Vec::new();
+        let mut highest = 0;
+        for (element, &rank) in elements.iter().zip(&ranks) {
+            if rank < highest {
+                results.push(LintResult::new(
+                    Some(element.clone()),
+                    Vec::new(),
+                    Some(format!(
+                        "Select target `{}` should come before targets of a lower priority.",
+                        element.raw().trim()
+                    )),
+                    None,
+                ));
+            }
+            highest = highest.max(rank);
+        }
+
+        if results.is_empty() || !elements.iter().all(is_plain_column) {
+            return results;
+        }
+
+        let mut ordered: Vec<_> = elements.iter().zip(ranks).collect();
+        ordered.sort_by_key(|(_, rank)| *rank);
+        results[0].fixes = elements
+            .iter()
+            .zip(ordered)
+            .filter(|(element, (replacement, _))| element != replacement)
+            .map(|(element, (replacement, _))| {
+                LintFix::replace(element.clone(), vec![replacement.clone()], None)
+            })
+            .collect();
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectClause]) }).into()
+    }
+}
+
+impl RuleST16 {
+    /// The index of the first pattern matching the select target, with
+    /// unmatched targets ranked last.
+    fn rank(&self, element: &ErasedSegment) -> usize {
+        let name = target_name(element).map(|it| it.to_lowercase());
+        name.and_then(|name| {
+            self.column_priorities
+                .iter()
+                .position(|pattern| pattern.matches(&name))
+        })
+        .unwrap_or(self.column_priorities.len())
+    }
+}
+
+/// The alias of a select target, or the column name of an unaliased column.
+fn target_name(element: &ErasedSegment) -> Option<String> {
+    if let Some(alias) = element.child(const { &SyntaxSet::new(&[SyntaxKind::AliasExpression]) }) {
+        return alias
+            .segments()
+            .iter()
+            .rev()
+            .find(|it| it.is_code())
+            .map(|it| it.raw().to_string());
+    }
+
+    let column = element.child(const { &SyntaxSet::new(&[SyntaxKind::ColumnReference]) })?;
+    column
+        .segments()
+        .iter()
+        .rev()
+        .find(|it| it.is_code())
+        .map(|it| it.raw().to_string())
+}
+
+fn is_plain_column(element: &ErasedSegment) -> bool {
+    let code: Vec<_> = element
+        .segments()
+        .iter()
+        .filter(|it| it.is_code())
+        .collect();
+    matches!(code.as_slice(), [column] if column.is_type(SyntaxKind::ColumnReference))
+}
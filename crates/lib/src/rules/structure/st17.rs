@@ -0,0 +1,169 @@
+use ahash::{AHashMap, AHashSet};
+use sqruff_lib_core::dialects::syntax::{SyntaxKind, SyntaxSet};
+use sqruff_lib_core::lint_fix::LintFix;
+use sqruff_lib_core::parser::segments::base::ErasedSegment;
+use sqruff_lib_core::parser::segments::from::FromExpressionElementSegment;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST17;
+
+impl Rule for RuleST17 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST17.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.uncorrelated_lateral"
+    }
+
+    fn description(&self) -> &'static str {
+        "LATERAL should only be used when referring to a preceding table."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+`LATERAL` is used on a table or subquery which does not refer to any of the
+tables before it, so it has no effect and only misleads the reader.
+
+```sql
+SELECT o.id, r.rate
+FROM orders AS o
+CROSS JOIN LATERAL (SELECT rate FROM exchange_rates WHERE currency = 'EUR') AS r
+```
+
+**Best practice**
+
+Drop `LATERAL`, and keep it for correlated subqueries and functions.
+
+```sql
+SELECT o.id, r.rate
+FROM orders AS o
+CROSS JOIN (SELECT rate FROM exchange_rates WHERE currency = 'EUR') AS r
+```
+
+Unqualified columns in the arguments of a function are assumed to refer to
+a preceding table, while those in a subquery are assumed to refer to the
+tables of the subquery.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: &RuleContext) -> Vec<LintResult> {
+        // Each element of the FROM clause, with the LATERAL keyword before it
+        // and the parent which holds the keyword.
+        let mut elements: Vec<(ErasedSegment, Option<(ErasedSegment, ErasedSegment)>)> = Vec::new();
+        for from_expression in context
+            .segment
+            .children(const { &SyntaxSet::new(&[SyntaxKind::FromExpression]) })
+        {
+            for child in from_expression.segments() {
+                let element = match child.get_type() {
+                    SyntaxKind::FromExpressionElement => child.clone(),
+                    SyntaxKind::JoinClause => {
+                        match child
+                            .child(const { &SyntaxSet::new(&[SyntaxKind::FromExpressionElement]) })
+                        {
+                            Some(element) => element,
+                            None => continue,
+                        }
+                    }
+                    _ => continue,
+                };
+
+                let lateral = [child, &element].into_iter().find_map(|parent| {
+                    parent
+                        .segments()
+                        .iter()
+                        .find(|it| it.is_keyword("LATERAL"))
+                        .map(|keyword| (parent.clone(), keyword.clone()))
+                });
+                elements.push((element, lateral));
+            }
+        }
+
+        let mut results = Vec::new();
+        let mut preceding = AHashSet::new();
+        for (element, lateral) in elements {
+            let lateral = lateral.filter(|_| !is_correlated(&element, &preceding));
+            if let Some((parent, keyword)) = lateral {
+                let mut fixes = vec![LintFix::delete(keyword.clone())];
+                let segments = parent.segments();
+                let whitespace = segments
+                    .iter()
+                    .skip_while(|it| **it != keyword)
+                    .nth(1)
+                    .filter(|it| it.is_type(SyntaxKind::Whitespace));
+                if let Some(whitespace) = whitespace {
+                    fixes.push(LintFix::delete(whitespace.clone()));
+                }
+
+                results.push(LintResult::new(
+                    Some(keyword),
+                    fixes,
+                    Some("LATERAL has no effect as no preceding table is referred to.".into()),
+                    None,
+                ));
+            }
+
+            preceding.insert(
+                FromExpressionElementSegment(element)
+                    .eventual_alias()
+                    .ref_str
+                    .to_uppercase(),
+            );
+        }
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::FromClause]) }).into()
+    }
+}
+
+fn is_correlated(element: &ErasedSegment, preceding: &AHashSet<String>) -> bool {
+    let qualified = element
+        .recursive_crawl(
+            const { &SyntaxSet::new(&[SyntaxKind::ColumnReference]) },
+            true,
+            &SyntaxSet::EMPTY,
+            false,
+        )
+        .into_iter()
+        .any(|reference| {
+            let parts = reference.reference().iter_raw_references();
+            matches!(
+                parts.as_slice(),
+                [.., qualifier, _] if preceding.contains(&qualifier.part.to_uppercase())
+            )
+        });
+    if qualified {
+        return true;
+    }
+
+    // Outside of a subquery, an unqualified column can only come from a
+    // preceding table.
+    element
+        .recursive_crawl(
+            const { &SyntaxSet::new(&[SyntaxKind::ColumnReference]) },
+            true,
+            const { &SyntaxSet::new(&[SyntaxKind::SelectStatement]) },
+            false,
+        )
+        .into_iter()
+        .any(|reference| reference.reference().iter_raw_references().len() == 1)
+}
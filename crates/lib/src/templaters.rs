@@ -5,6 +5,8 @@ use sqruff_lib_core::templaters::base::TemplatedFile;
 
 use crate::cli::formatters::Formatter;
 use crate::core::config::FluffConfig;
+use crate::templaters::env::EnvTemplater;
+use crate::templaters::jinja_native::NativeJinjaTemplater;
 use crate::templaters::placeholder::PlaceholderTemplater;
 use crate::templaters::raw::RawTemplater;
 
@@ -15,8 +17,10 @@ use crate::templaters::python::PythonTemplater;
 
 #[cfg(feature = "python")]
 pub mod dbt;
+pub mod env;
 #[cfg(feature = "python")]
 pub mod jinja;
+pub mod jinja_native;
 pub mod placeholder;
 #[cfg(feature = "python")]
 pub mod python;
@@ -26,6 +30,8 @@ pub mod raw;
 
 pub static RAW_TEMPLATER: RawTemplater = RawTemplater;
 pub static PLACEHOLDER_TEMPLATER: PlaceholderTemplater = PlaceholderTemplater;
+pub static ENV_TEMPLATER: EnvTemplater = EnvTemplater;
+pub static MINIJINJA_TEMPLATER: NativeJinjaTemplater = NativeJinjaTemplater;
 #[cfg(feature = "python")]
 pub static PYTHON_TEMPLATER: PythonTemplater = PythonTemplater;
 #[cfg(feature = "python")]
@@ -35,16 +41,23 @@ pub static DBT_TEMPLATER: dbt::DBTTemplater = dbt::DBTTemplater;
 
 // templaters returns all the templaters that are available in the library
 #[cfg(feature = "python")]
-pub static TEMPLATERS: [&'static dyn Templater; 5] = [
+pub static TEMPLATERS: [&'static dyn Templater; 7] = [
     &RAW_TEMPLATER,
     &PLACEHOLDER_TEMPLATER,
+    &ENV_TEMPLATER,
     &PYTHON_TEMPLATER,
     &JINJA_TEMPLATER,
+    &MINIJINJA_TEMPLATER,
     &DBT_TEMPLATER,
 ];
 
 #[cfg(not(feature = "python"))]
-pub static TEMPLATERS: [&'static dyn Templater; 2] = [&RAW_TEMPLATER, &PLACEHOLDER_TEMPLATER];
+pub static TEMPLATERS: [&'static dyn Templater; 4] = [
+    &RAW_TEMPLATER,
+    &PLACEHOLDER_TEMPLATER,
+    &ENV_TEMPLATER,
+    &MINIJINJA_TEMPLATER,
+];
 
 pub trait Templater: Send + Sync {
     /// The name of the templater.
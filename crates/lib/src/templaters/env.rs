@@ -0,0 +1,250 @@
+use std::sync::{Arc, LazyLock};
+
+use ahash::AHashSet;
+use regex::Regex;
+use sqruff_lib_core::errors::SQLFluffUserError;
+use sqruff_lib_core::templaters::base::{RawFileSlice, TemplatedFile, TemplatedFileSlice};
+
+use crate::cli::formatters::Formatter;
+use crate::core::config::FluffConfig;
+use crate::templaters::Templater;
+
+/// Matches `$VAR` and `${VAR}`, as well as the `$tag$` delimiters of dollar quoted strings so
+/// that they can be skipped.
+static VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\$(?:[A-Za-z_]\w*)?\$|\$(?:\{(?P<braced>[A-Za-z_]\w*)\}|(?P<bare>[A-Za-z_]\w*))")
+        .unwrap()
+});
+
+#[derive(Default)]
+pub struct EnvTemplater;
+
+impl EnvTemplater {
+    /// The variables to substitute, or `None` to substitute all of them.
+    fn allowlist(&self, config: &FluffConfig) -> Option<AHashSet<String>> {
+        let allowlist = config
+            .get("env", "templater")
+            .as_map()?
+            .get("allowlist")?
+            .as_array()?;
+
+        Some(
+            allowlist
+                .iter()
+                .filter_map(|it| it.as_string())
+                .map(|it| it.trim().to_string())
+                .filter(|it| !it.is_empty())
+                .collect(),
+        )
+    }
+}
+
+impl Templater for EnvTemplater {
+    fn name(&self) -> &'static str {
+        "env"
+    }
+
+    fn description(&self) -> &'static str {
+        r#"Deployment scripts are often passed through `envsubst` before they are run, which replaces `$VAR` and `${VAR}`
+with the value of the environment variable `VAR`. The env templater does the same substitution before parsing, so these
+scripts can be linted as is.
+
+```sql
+CREATE SCHEMA ${ENVIRONMENT}_marketing;
+```
+
+Env templating can be enabled in the config using:
+
+```ini
+[sqruff]
+templater = env
+```
+
+Variables which are not set are left untouched, as are positional parameters such as `$1` and the `$tag$` delimiters of
+dollar quoted strings. To only replace some variables, list them in `allowlist`. Like `envsubst`, listed variables which
+are not set are replaced with an empty string:
+
+```ini
+[sqruff:templater:env]
+allowlist = ENVIRONMENT,DATABASE
+```"#
+    }
+
+    fn process(
+        &self,
+        in_str: &str,
+        f_name: &str,
+        config: &FluffConfig,
+        _: &Option<Arc<dyn Formatter>>,
+    ) -> Result<TemplatedFile, SQLFluffUserError> {
+        let allowlist = self.allowlist(config);
+
+        let mut template_slices = vec![];
+        let mut raw_slices = vec![];
+        let mut last_pos_raw = 0usize;
+        let mut last_pos_templated = 0;
+        let mut out_str = String::with_capacity(in_str.len());
+
+        for cap in VARIABLE.captures_iter(in_str) {
+            let span = cap.get(0).unwrap().range();
+            let Some(name) = cap.name("braced").or_else(|| cap.name("bare")) else {
+                continue;
+            };
+
+            let replacement = match &allowlist {
+                Some(allowlist) if allowlist.contains(name.as_str()) => {
+                    std::env::var(name.as_str()).unwrap_or_default()
+                }
+                Some(_) => continue,
+                None => match std::env::var(name.as_str()) {
+                    Ok(value) => value,
+                    Err(_) => continue,
+                },
+            };
+
+            if span.start > last_pos_raw {
+                let literal = &in_str[last_pos_raw..span.start];
+                template_slices.push(TemplatedFileSlice {
+                    slice_type: "literal".to_string(),
+                    source_slice: last_pos_raw..span.start,
+                    templated_slice: last_pos_templated..last_pos_templated + literal.len(),
+                });
+                raw_slices.push(RawFileSlice::new(
+                    literal.to_string(),
+                    "literal".to_string(),
+                    last_pos_raw,
+                    None,
+                    None,
+                ));
+                out_str.push_str(literal);
+                last_pos_templated += literal.len();
+            }
+
+            template_slices.push(TemplatedFileSlice {
+                slice_type: "templated".to_string(),
+                source_slice: span.clone(),
+                templated_slice: last_pos_templated..last_pos_templated + replacement.len(),
+            });
+            raw_slices.push(RawFileSlice::new(
+                in_str[span.clone()].to_string(),
+                "templated".to_string(),
+                span.start,
+                None,
+                None,
+            ));
+            out_str.push_str(&replacement);
+
+            last_pos_raw = span.end;
+            last_pos_templated += replacement.len();
+        }
+
+        if in_str.len() > last_pos_raw {
+            template_slices.push(TemplatedFileSlice {
+                slice_type: "literal".to_string(),
+                source_slice: last_pos_raw..in_str.len(),
+                templated_slice: last_pos_templated
+                    ..last_pos_templated + (in_str.len() - last_pos_raw),
+            });
+            raw_slices.push(RawFileSlice::new(
+                in_str[last_pos_raw..].to_string(),
+                "literal".to_string(),
+                last_pos_raw,
+                None,
+                None,
+            ));
+            out_str.push_str(&in_str[last_pos_raw..]);
+        }
+
+        TemplatedFile::new(
+            in_str.to_string(),
+            f_name.to_string(),
+            Some(out_str),
+            Some(template_slices),
+            Some(raw_slices),
+        )
+        .map_err(|e| SQLFluffUserError::new(format!("Failed to template file: {e:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templater_env() {
+        std::env::set_var("SQRUFF_TEST_ENV_SCHEMA", "analytics");
+        std::env::remove_var("SQRUFF_TEST_ENV_UNSET");
+
+        let config = FluffConfig::from_source("[sqruff]\ntemplater = env\n", None);
+        let templated_file = EnvTemplater
+            .process(
+                "SELECT a$SQRUFF_TEST_ENV_UNSET FROM ${SQRUFF_TEST_ENV_SCHEMA}.orders\n",
+                "test.sql",
+                &config,
+                &None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            templated_file.templated(),
+            "SELECT a$SQRUFF_TEST_ENV_UNSET FROM analytics.orders\n"
+        );
+        let position = templated_file.templated().find(".orders").unwrap();
+        let source_slice = templated_file
+            .templated_slice_to_source_slice(position..position + 7)
+            .unwrap();
+        assert_eq!(&templated_file.source_str[source_slice], ".orders");
+    }
+
+    #[test]
+    fn test_templater_env_allowlist() {
+        std::env::set_var("SQRUFF_TEST_ENV_ALLOWED", "orders");
+        std::env::set_var("SQRUFF_TEST_ENV_IGNORED", "customers");
+
+        let config = FluffConfig::from_source(
+            "
+[sqruff]
+templater = env
+
+[sqruff:templater:env]
+allowlist = SQRUFF_TEST_ENV_ALLOWED",
+            None,
+        );
+        let templated_file = EnvTemplater
+            .process(
+                "SELECT * FROM $SQRUFF_TEST_ENV_ALLOWED JOIN $SQRUFF_TEST_ENV_IGNORED USING (id)\n",
+                "test.sql",
+                &config,
+                &None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            templated_file.templated(),
+            "SELECT * FROM orders JOIN $SQRUFF_TEST_ENV_IGNORED USING (id)\n"
+        );
+    }
+
+    #[test]
+    fn test_templater_env_dollar_quotes() {
+        std::env::set_var("SQRUFF_TEST_ENV_RATE", "0.2");
+        std::env::set_var("sqruff_test_env_tag", "oops");
+
+        let config = FluffConfig::from_source("[sqruff]\ntemplater = env\n", None);
+        let templated_file = EnvTemplater
+            .process(
+                "CREATE FUNCTION tax(numeric) RETURNS numeric AS $sqruff_test_env_tag$\n    \
+                 SELECT $1 * $SQRUFF_TEST_ENV_RATE\n$sqruff_test_env_tag$ LANGUAGE sql;\n",
+                "test.sql",
+                &config,
+                &None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            templated_file.templated(),
+            "CREATE FUNCTION tax(numeric) RETURNS numeric AS $sqruff_test_env_tag$\n    \
+             SELECT $1 * 0.2\n$sqruff_test_env_tag$ LANGUAGE sql;\n"
+        );
+    }
+}
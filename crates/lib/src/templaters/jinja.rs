@@ -19,7 +19,35 @@ impl Templater for JinjaTemplater {
     }
 
     fn description(&self) -> &'static str {
-        "Not fully implemented yet. More details to come."
+        r"**Note:** This templater currently does not work by default in the CLI and needs custom set up to work. The
+minijinja templater renders the same templates without Python.
+
+The Jinja templater renders `{{ ... }}` expressions and `{% ... %}` blocks with Python's Jinja2 before the SQL is
+linted. Violations found in the rendered SQL are reported against the lines of the original template. An example
+would be as follows:
+
+```sql
+SELECT {{ column }} FROM {{ table }}
+```
+
+With the following config:
+
+```
+[sqruff]
+templater = jinja
+
+[sqruff:templater:jinja:context]
+column = id
+table = users
+```
+
+Before parsing the sql will be transformed to:
+
+```sql
+SELECT id FROM users
+```
+
+Context values are always passed to the template as strings."
     }
 
     fn process(
@@ -118,4 +146,41 @@ FROM events
             "\n\n\nSELECT\n    event_id\n    \n    , campaign\n    \n    , click_item\n    \nFROM events\n"
         )
     }
+
+    #[test]
+    fn test_jinja_templater_context() {
+        let source = r"
+    [sqruff]
+    templater = jinja
+
+    [sqruff:templater:jinja:context]
+    table = users
+        ";
+        let config = FluffConfig::from_source(source, None);
+        let templater = JinjaTemplater;
+
+        let processed = templater
+            .process(
+                "SELECT id\nFROM {{ table }}\nWHERE id = 1\n",
+                "test.sql",
+                &config,
+                &None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            processed.templated(),
+            "SELECT id\nFROM users\nWHERE id = 1\n"
+        );
+        // `WHERE` starts at a different offset once rendered, but maps back
+        // to the third line of the template.
+        let position = processed.templated().find("WHERE").unwrap();
+        let source_slice = processed
+            .templated_slice_to_source_slice(position..position + 5)
+            .unwrap();
+        assert_eq!(
+            processed.get_line_pos_of_char_pos(source_slice.start, true),
+            (3, 1)
+        );
+    }
 }
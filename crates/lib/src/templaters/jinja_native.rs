@@ -0,0 +1,607 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use minijinja::Environment;
+use sqruff_lib_core::errors::SQLFluffUserError;
+use sqruff_lib_core::templaters::base::{RawFileSlice, TemplatedFile, TemplatedFileSlice};
+
+use crate::cli::formatters::Formatter;
+use crate::core::config::FluffConfig;
+use crate::templaters::Templater;
+
+/// Surround the index of a literal in the traced template, so that it can be found in the
+/// rendered output.
+const MARKER_START: char = '\u{1}';
+const MARKER_END: char = '\u{2}';
+
+/// Renders Jinja templates with [minijinja](https://docs.rs/minijinja), so that they can be
+/// linted without Python.
+#[derive(Default)]
+pub struct NativeJinjaTemplater;
+
+impl NativeJinjaTemplater {
+    fn context(config: &FluffConfig) -> BTreeMap<String, String> {
+        config
+            .get("minijinja", "templater")
+            .as_map()
+            .and_then(|templater| templater.get("context"))
+            .and_then(|context| context.as_map())
+            .map(|context| {
+                context
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Templater for NativeJinjaTemplater {
+    fn name(&self) -> &'static str {
+        "minijinja"
+    }
+
+    fn description(&self) -> &'static str {
+        r"The minijinja templater renders Jinja `{{ ... }}` expressions and `{% ... %}` blocks with
+[minijinja](https://docs.rs/minijinja) before the SQL is linted. Unlike the jinja templater, it doesn't need Python,
+so it works in every build of sqruff. Violations found in the rendered SQL are reported against the lines of the
+original template. An example would be as follows:
+
+```sql
+SELECT {{ column }} FROM {{ table }}
+```
+
+With the following config:
+
+```
+[sqruff]
+templater = minijinja
+
+[sqruff:templater:minijinja:context]
+column = id
+table = users
+```
+
+Before parsing the sql will be transformed to:
+
+```sql
+SELECT id FROM users
+```
+
+Context values are always passed to the template as strings."
+    }
+
+    fn process(
+        &self,
+        in_str: &str,
+        f_name: &str,
+        config: &FluffConfig,
+        _: &Option<Arc<dyn Formatter>>,
+    ) -> Result<TemplatedFile, SQLFluffUserError> {
+        let context = Self::context(config);
+        let mut env = Environment::new();
+        env.set_keep_trailing_newline(true);
+
+        let render = |template: &str| {
+            env.render_str(template, &context)
+                .map_err(|err| SQLFluffUserError::new(format!("Jinja templater error: {err}")))
+        };
+
+        let templated_str = render(in_str)?;
+        let tokens = tokenize(in_str);
+
+        // Every literal of the template is preceded by a marker, so that the rendered output can
+        // be traced back to the parts of the template which produced it.
+        let traced = if in_str.contains([MARKER_START, MARKER_END]) {
+            None
+        } else {
+            Some(render(&traced_template(in_str, &tokens))?)
+        };
+
+        let (sliced_file, raw_sliced) = match traced
+            .map(|traced| slice(in_str, &tokens, &traced))
+            .filter(|(_, traced_str)| *traced_str == templated_str)
+        {
+            Some((sliced_file, _)) => (sliced_file, raw_slices(in_str, &tokens)),
+            // The output couldn't be traced, e.g. because the template measured one of its
+            // literals, so the whole file is treated as one templated slice.
+            None => (
+                vec![TemplatedFileSlice::new(
+                    "templated",
+                    0..in_str.len(),
+                    0..templated_str.len(),
+                )],
+                vec![RawFileSlice::new(
+                    in_str.to_string(),
+                    "templated".to_string(),
+                    0,
+                    None,
+                    None,
+                )],
+            ),
+        };
+
+        TemplatedFile::new(
+            in_str.to_string(),
+            f_name.to_string(),
+            Some(templated_str),
+            Some(sliced_file),
+            Some(raw_sliced),
+        )
+        .map_err(|e| SQLFluffUserError::new(format!("Failed to template file: {e:?}")))
+    }
+}
+
+/// A part of the template, in the terms of [`RawFileSlice`].
+#[derive(Debug)]
+struct Token {
+    slice_type: &'static str,
+    /// The span of the token in the template, including the whitespace trimmed by its `-`
+    /// modifiers.
+    source: Range<usize>,
+    /// The span of the tag itself, without the whitespace it trims.
+    tag: Range<usize>,
+    trim_before: bool,
+    trim_after: bool,
+}
+
+impl Token {
+    fn is_literal(&self) -> bool {
+        self.slice_type == "literal"
+    }
+}
+
+/// Splits the template into literals, `{{ ... }}` expressions, `{% ... %}` statements and
+/// `{# ... #}` comments. The whitespace trimmed by the `-` modifier of a tag is part of the tag,
+/// as it doesn't appear in the output.
+fn tokenize(in_str: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    let mut in_raw = false;
+
+    while pos < in_str.len() {
+        let Some(start) = find_tag_start(in_str, pos, in_raw) else {
+            push_literal(&mut tokens, pos..in_str.len());
+            break;
+        };
+        push_literal(&mut tokens, pos..start);
+
+        let (open, close) = match &in_str[start + 1..start + 2] {
+            "{" => ("{{", "}}"),
+            "%" => ("{%", "%}"),
+            _ => ("{#", "#}"),
+        };
+        let end = find_tag_end(in_str, start + open.len(), close).unwrap_or(in_str.len());
+        let inner = &in_str[start + open.len()..end.saturating_sub(close.len()).max(start + 2)];
+
+        let slice_type = match open {
+            "{{" => "templated",
+            "{#" => "comment",
+            _ => statement_type(inner.trim_matches(|c: char| c == '-' || c == '+')),
+        };
+        if open == "{%" {
+            let keyword = first_word(inner);
+            in_raw = keyword == "raw" || (in_raw && keyword != "endraw");
+        }
+
+        tokens.push(Token {
+            slice_type,
+            source: start..end,
+            tag: start..end,
+            trim_before: inner.starts_with('-'),
+            trim_after: inner.len() > 1 && inner.ends_with('-'),
+        });
+        pos = end;
+    }
+
+    // Move the whitespace trimmed by tags out of the literals next to them.
+    for idx in 0..tokens.len() {
+        if tokens[idx].is_literal() {
+            continue;
+        }
+
+        if tokens[idx].trim_before && idx > 0 && tokens[idx - 1].is_literal() {
+            let literal = tokens[idx - 1].source.clone();
+            let trimmed = in_str[literal.clone()].trim_end().len();
+            tokens[idx - 1].source.end = literal.start + trimmed;
+            tokens[idx].source.start = literal.start + trimmed;
+        }
+        if tokens[idx].trim_after && idx + 1 < tokens.len() && tokens[idx + 1].is_literal() {
+            let literal = tokens[idx + 1].source.clone();
+            let trimmed = literal.len() - in_str[literal.clone()].trim_start().len();
+            tokens[idx + 1].source.start = literal.start + trimmed;
+            tokens[idx].source.end = literal.start + trimmed;
+        }
+    }
+    tokens.retain(|token| !token.source.is_empty());
+
+    tokens
+}
+
+fn push_literal(tokens: &mut Vec<Token>, source: Range<usize>) {
+    if !source.is_empty() {
+        tokens.push(Token {
+            slice_type: "literal",
+            source: source.clone(),
+            tag: source,
+            trim_before: false,
+            trim_after: false,
+        });
+    }
+}
+
+fn find_tag_start(in_str: &str, from: usize, in_raw: bool) -> Option<usize> {
+    let mut search = from;
+    loop {
+        let start = search + in_str[search..].find('{')?;
+        let next = in_str[start + 1..].chars().next()?;
+        let is_tag = if in_raw {
+            // Only `{% endraw %}` ends a raw block.
+            next == '%' && first_word(&in_str[start + 2..]) == "endraw"
+        } else {
+            matches!(next, '{' | '%' | '#')
+        };
+        if is_tag {
+            return Some(start);
+        }
+        search = start + 1;
+    }
+}
+
+/// Finds the end of a tag, skipping over the string literals inside it.
+fn find_tag_end(in_str: &str, from: usize, close: &str) -> Option<usize> {
+    let mut quote = None;
+    let mut chars = in_str[from..].char_indices();
+    while let Some((offset, c)) = chars.next() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) if c == '\\' => {
+                chars.next();
+            }
+            Some(_) => {}
+            None if close != "#}" && (c == '\'' || c == '"') => quote = Some(c),
+            None if in_str[from + offset..].starts_with(close) => {
+                return Some(from + offset + close.len());
+            }
+            None => {}
+        }
+    }
+    None
+}
+
+fn first_word(inner: &str) -> &str {
+    let inner = inner.trim_start_matches(['-', '+']).trim_start();
+    let end = inner
+        .find(|c: char| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(inner.len());
+    &inner[..end]
+}
+
+fn statement_type(inner: &str) -> &'static str {
+    match first_word(inner) {
+        keyword if keyword.starts_with("end") => "block_end",
+        "elif" | "else" => "block_mid",
+        "if" | "for" | "macro" | "call" | "filter" | "raw" | "with" | "autoescape" | "block" => {
+            "block_start"
+        }
+        // `{% set x %}...{% endset %}` captures a block, `{% set x = ... %}` doesn't.
+        "set" if !inner.contains('=') => "block_start",
+        _ => "templated",
+    }
+}
+
+/// The template with a marker before each literal, and without the `-` modifiers of the tags
+/// since the whitespace they trim is already left out.
+fn traced_template(in_str: &str, tokens: &[Token]) -> String {
+    let mut traced = String::with_capacity(in_str.len() * 2);
+    for (idx, token) in tokens.iter().enumerate() {
+        if token.is_literal() {
+            traced.push(MARKER_START);
+            traced.push_str(&idx.to_string());
+            traced.push(MARKER_END);
+            traced.push_str(&in_str[token.source.clone()]);
+            continue;
+        }
+
+        let tag = &in_str[token.tag.clone()];
+        let (open, rest) = tag.split_at(2);
+        let (inner, close) = rest.split_at(rest.len().saturating_sub(2));
+        let inner = if token.trim_before {
+            format!(" {}", &inner[1..])
+        } else {
+            inner.to_string()
+        };
+        let inner = if token.trim_after {
+            format!("{} ", &inner[..inner.len() - 1])
+        } else {
+            inner
+        };
+        traced.push_str(open);
+        traced.push_str(&inner);
+        traced.push_str(close);
+    }
+    traced
+}
+
+/// Maps the rendered output of the traced template back to the template, returning the slices
+/// and the output without its markers.
+fn slice(in_str: &str, tokens: &[Token], traced: &str) -> (Vec<TemplatedFileSlice>, String) {
+    let mut sliced_file = Vec::new();
+    let mut templated_str = String::with_capacity(traced.len());
+    let mut previous = None;
+    let mut rest = traced;
+
+    loop {
+        let (output, next) = match rest.find(MARKER_START) {
+            Some(start) => {
+                let end = start + rest[start..].find(MARKER_END).unwrap();
+                let idx: usize = rest[start + 1..end].parse().unwrap();
+                (&rest[..start], Some((idx, &rest[end + 1..])))
+            }
+            None => (rest, None),
+        };
+
+        let between = tokens_between(tokens, previous, next.map(|(idx, _)| idx));
+        push_tags(
+            &mut sliced_file,
+            &mut templated_str,
+            tokens,
+            &between,
+            output,
+        );
+
+        let Some((idx, after)) = next else {
+            break;
+        };
+        let literal = &in_str[tokens[idx].source.clone()];
+        previous = Some(idx);
+        rest = match after.strip_prefix(literal) {
+            Some(after) => {
+                let start = templated_str.len();
+                templated_str.push_str(literal);
+                sliced_file.push(TemplatedFileSlice::new(
+                    "literal",
+                    tokens[idx].source.clone(),
+                    start..templated_str.len(),
+                ));
+                after
+            }
+            // The literal was changed while rendering, so it is only part of the output of the
+            // tags around it.
+            None => after,
+        };
+    }
+
+    (sliced_file, templated_str)
+}
+
+/// The tags rendered between two literals of the output. When the output goes back in the
+/// template, as at the end of a loop, these are the tags after the previous literal and the
+/// tags before the next one.
+fn tokens_between(tokens: &[Token], previous: Option<usize>, next: Option<usize>) -> Vec<usize> {
+    let after_previous = previous.map_or(0, |idx| idx + 1);
+    let next_or_end = next.unwrap_or(tokens.len());
+
+    let indices = if after_previous <= next_or_end {
+        (after_previous..next_or_end).collect()
+    } else {
+        let next_literal = (after_previous..tokens.len())
+            .find(|&idx| tokens[idx].is_literal())
+            .unwrap_or(tokens.len());
+        let previous_literal = (0..next_or_end)
+            .rev()
+            .find(|&idx| tokens[idx].is_literal())
+            .map_or(0, |idx| idx + 1);
+        (after_previous..next_literal)
+            .chain(previous_literal..next_or_end)
+            .collect::<Vec<_>>()
+    };
+
+    indices
+        .into_iter()
+        .filter(|&idx| !tokens[idx].is_literal())
+        .collect()
+}
+
+fn push_tags(
+    sliced_file: &mut Vec<TemplatedFileSlice>,
+    templated_str: &mut String,
+    tokens: &[Token],
+    between: &[usize],
+    output: &str,
+) {
+    let position = templated_str.len();
+    let zero_length = |idx: usize| {
+        TemplatedFileSlice::new(
+            tokens[idx].slice_type,
+            tokens[idx].source.clone(),
+            position..position,
+        )
+    };
+
+    if output.is_empty() {
+        sliced_file.extend(between.iter().map(|&idx| zero_length(idx)));
+        return;
+    }
+
+    templated_str.push_str(output);
+    let templated_slice = position..templated_str.len();
+
+    // The output is put down to the expressions, the tags around them rendering nothing.
+    let expressions = between
+        .iter()
+        .positions(|&idx| tokens[idx].slice_type == "templated")
+        .collect::<Vec<_>>();
+    let Some(&last) = expressions.last() else {
+        let source = between
+            .last()
+            .map_or(0..0, |&idx| tokens[idx].source.clone());
+        sliced_file.push(TemplatedFileSlice::new(
+            "templated",
+            source,
+            templated_slice,
+        ));
+        return;
+    };
+    // Only the expressions which are in order in the template share the output.
+    let first = expressions
+        .iter()
+        .rev()
+        .take_while(|&&position| between[position] <= between[last])
+        .last()
+        .copied()
+        .unwrap_or(last);
+
+    sliced_file.extend(between[..first].iter().map(|&idx| zero_length(idx)));
+    sliced_file.push(TemplatedFileSlice::new(
+        "templated",
+        tokens[between[first]].source.start..tokens[between[last]].source.end,
+        templated_slice.clone(),
+    ));
+    let end = templated_slice.end;
+    sliced_file.extend(between[last + 1..].iter().map(|&idx| {
+        TemplatedFileSlice::new(tokens[idx].slice_type, tokens[idx].source.clone(), end..end)
+    }));
+}
+
+fn raw_slices(in_str: &str, tokens: &[Token]) -> Vec<RawFileSlice> {
+    let mut block_idx = 0;
+    tokens
+        .iter()
+        .map(|token| {
+            if matches!(token.slice_type, "block_start" | "block_mid" | "block_end") {
+                block_idx += 1;
+            }
+            RawFileSlice::new(
+                in_str[token.source.clone()].to_string(),
+                token.slice_type.to_string(),
+                token.source.start,
+                None,
+                Some(block_idx),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::linter::core::Linter;
+
+    const JINJA_STRING: &str = "
+{% set event_columns = ['campaign', 'click_item'] %}
+
+SELECT
+    event_id
+    {% for event_column in event_columns %}
+    , {{ event_column }}
+    {% endfor %}
+FROM events
+";
+
+    fn process(in_str: &str, config: &str) -> TemplatedFile {
+        let config = FluffConfig::from_source(config, None);
+        NativeJinjaTemplater
+            .process(in_str, "test.sql", &config, &None)
+            .unwrap()
+    }
+
+    fn source_of<'a>(templated_file: &'a TemplatedFile, needle: &str) -> &'a str {
+        let position = templated_file.templated().find(needle).unwrap();
+        let source_slice = templated_file
+            .templated_slice_to_source_slice(position..position + needle.len())
+            .unwrap();
+        &templated_file.source_str[source_slice]
+    }
+
+    #[test]
+    fn test_native_jinja_templater() {
+        let templated_file = process(JINJA_STRING, "[sqruff]\ntemplater = minijinja\n");
+
+        assert_eq!(
+            templated_file.templated(),
+            "\n\n\nSELECT\n    event_id\n    \n    , campaign\n    \n    , click_item\n    \nFROM events\n"
+        );
+        assert_eq!(source_of(&templated_file, "FROM events"), "FROM events");
+        assert_eq!(
+            source_of(&templated_file, "click_item"),
+            "{{ event_column }}"
+        );
+    }
+
+    #[test]
+    fn test_native_jinja_templater_context() {
+        let templated_file = process(
+            "SELECT id\nFROM {{ table }}\nWHERE id = {{ id }}\n",
+            "[sqruff]\ntemplater = minijinja\n\n[sqruff:templater:minijinja:context]\ntable = users\nid = 1\n",
+        );
+
+        assert_eq!(
+            templated_file.templated(),
+            "SELECT id\nFROM users\nWHERE id = 1\n"
+        );
+        // `WHERE` starts at a different offset once rendered, but maps back
+        // to the third line of the template.
+        let position = templated_file.templated().find("WHERE").unwrap();
+        let source_slice = templated_file
+            .templated_slice_to_source_slice(position..position + 5)
+            .unwrap();
+        assert_eq!(
+            templated_file.get_line_pos_of_char_pos(source_slice.start, true),
+            (3, 1)
+        );
+    }
+
+    #[test]
+    fn test_native_jinja_templater_whitespace_control() {
+        let templated_file = process(
+            "SELECT\n    {%- if true %} a {%- else %} b {%- endif %}\nFROM t {# comment #}\n",
+            "[sqruff]\ntemplater = minijinja\n",
+        );
+
+        assert_eq!(templated_file.templated(), "SELECT a\nFROM t \n");
+        assert_eq!(source_of(&templated_file, "FROM t"), "FROM t");
+    }
+
+    #[test]
+    fn test_native_jinja_templater_raw_block() {
+        let templated_file = process(
+            "SELECT '{% raw %}{{ not_rendered }}{% endraw %}' AS a\n",
+            "[sqruff]\ntemplater = minijinja\n",
+        );
+
+        assert_eq!(
+            templated_file.templated(),
+            "SELECT '{{ not_rendered }}' AS a\n"
+        );
+        assert_eq!(source_of(&templated_file, "' AS a"), "' AS a");
+    }
+
+    #[test]
+    fn test_native_jinja_templater_filter_block() {
+        let templated_file = process(
+            "{% filter upper %}select a{% endfilter %} from t\n",
+            "[sqruff]\ntemplater = minijinja\n",
+        );
+
+        assert_eq!(templated_file.templated(), "SELECT A from t\n");
+    }
+
+    #[test]
+    fn test_native_jinja_templater_error() {
+        let config = FluffConfig::from_source("[sqruff]\ntemplater = minijinja\n", None);
+        let error = NativeJinjaTemplater
+            .process("SELECT {{ a\n", "test.sql", &config, &None)
+            .unwrap_err();
+
+        assert!(error.value.starts_with("Jinja templater error:"));
+    }
+
+    #[test]
+    fn test_native_jinja_templater_registered() {
+        let config = FluffConfig::from_source("[sqruff]\ntemplater = minijinja\n", None);
+        assert_eq!(Linter::get_templater(&config).name(), "minijinja");
+    }
+}
@@ -725,4 +725,44 @@ param_style = percent
 
         assert_eq!(result, "SELECT\n    a,\n    b\nFROM users WHERE a = %s\n");
     }
+
+    #[test]
+    /// Test that fixes keep the placeholders of each style in place.
+    fn test_templater_placeholder_fix_styles() {
+        let cases = [
+            ("colon", ":user_id"),
+            ("pyformat", "%(user_id)s"),
+            ("numeric_dollar", "$1"),
+            ("question_mark", "?"),
+        ];
+
+        for (param_style, placeholder) in cases {
+            let config = FluffConfig::from_source(
+                &format!(
+                    r#"
+[sqruff]
+dialect = ansi
+templater = placeholder
+rules = all
+
+[sqruff:templater:placeholder]
+param_style = {param_style}
+user_id = 1
+"#
+                ),
+                None,
+            );
+            let sql = format!("SELECT a,b FROM users WHERE a = {placeholder}");
+
+            let mut linter = Linter::new(config, None, None, false);
+            let mut result = linter.lint_string_wrapped(&sql, None, true);
+            let result = take(&mut result.paths[0].files[0]).fix_string();
+
+            assert_eq!(
+                result,
+                format!("SELECT\n    a,\n    b\nFROM users WHERE a = {placeholder}\n"),
+                "{param_style}"
+            );
+        }
+    }
 }
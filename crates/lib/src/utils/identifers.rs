@@ -1,3 +1,4 @@
+use sqruff_lib_core::dialects::init::DialectKind;
 use sqruff_lib_core::dialects::syntax::SyntaxKind;
 use sqruff_lib_core::parser::segments::base::ErasedSegment;
 
@@ -26,3 +27,51 @@ pub fn identifiers_policy_applicable(policy: &str, parent_stack: &[ErasedSegment
         }
     }
 }
+
+/// Whether two identifiers refer to the same name in the given dialect.
+///
+/// Unquoted identifiers are folded to the dialect's default case before being
+/// compared with quoted ones, so `"col"` and `"COL"` only match in dialects
+/// which ignore case altogether.
+pub fn identifiers_match(dialect: DialectKind, a: &ErasedSegment, b: &ErasedSegment) -> bool {
+    let (a_quoted, a_name) = identifier_name(a);
+    let (b_quoted, b_name) = identifier_name(b);
+
+    match dialect {
+        DialectKind::Athena
+        | DialectKind::Bigquery
+        | DialectKind::Databricks
+        | DialectKind::Duckdb
+        | DialectKind::Mysql
+        | DialectKind::Sparksql
+        | DialectKind::Sqlite
+        | DialectKind::Trino
+        | DialectKind::Tsql => a_name.eq_ignore_ascii_case(b_name),
+        DialectKind::Clickhouse => a_name == b_name,
+        DialectKind::Postgres | DialectKind::Redshift => {
+            fold_identifier(a_name, a_quoted, str::to_lowercase)
+                == fold_identifier(b_name, b_quoted, str::to_lowercase)
+        }
+        DialectKind::Ansi | DialectKind::Snowflake => {
+            fold_identifier(a_name, a_quoted, str::to_uppercase)
+                == fold_identifier(b_name, b_quoted, str::to_uppercase)
+        }
+    }
+}
+
+fn identifier_name(segment: &ErasedSegment) -> (bool, &str) {
+    let raw = segment.raw().as_str();
+    if segment.is_type(SyntaxKind::QuotedIdentifier) && raw.len() >= 2 {
+        (true, &raw[1..raw.len() - 1])
+    } else {
+        (false, raw)
+    }
+}
+
+fn fold_identifier(name: &str, quoted: bool, fold: fn(&str) -> String) -> String {
+    if quoted {
+        name.to_string()
+    } else {
+        fold(name)
+    }
+}
@@ -1,18 +1,22 @@
+use std::collections::HashMap;
+
 use ahash::AHashMap;
 use lsp_server::{Connection, Message, Request, RequestId, Response};
 use lsp_types::notification::{
     DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
     Notification, PublishDiagnostics,
 };
-use lsp_types::request::{Formatting, Request as _};
+use lsp_types::request::{CodeActionRequest, Formatting, Request as _};
 use lsp_types::{
-    Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-    DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentFormattingParams,
-    InitializeParams, InitializeResult, NumberOrString, OneOf, Position, PublishDiagnosticsParams,
-    Registration, ServerCapabilities, TextDocumentIdentifier, TextDocumentItem,
-    TextDocumentSyncCapability, TextDocumentSyncKind, Uri, VersionedTextDocumentIdentifier,
+    CodeAction, CodeActionContext, CodeActionKind, CodeActionOptions, CodeActionOrCommand,
+    CodeActionParams, CodeActionProviderCapability, Diagnostic, DiagnosticSeverity,
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams, DocumentFormattingParams, InitializeParams, InitializeResult,
+    NumberOrString, OneOf, Position, PublishDiagnosticsParams, Registration, ServerCapabilities,
+    TextDocumentIdentifier, TextDocumentItem, TextDocumentSyncCapability, TextDocumentSyncKind,
+    Uri, VersionedTextDocumentIdentifier, WorkspaceEdit,
 };
-use serde_json::Value;
+use serde_json::{json, Value};
 use sqruff_lib::core::config::FluffConfig;
 use sqruff_lib::core::linter::core::Linter;
 use wasm_bindgen::prelude::*;
@@ -32,6 +36,11 @@ fn server_initialize_result() -> InitializeResult {
         capabilities: ServerCapabilities {
             text_document_sync: TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL).into(),
             document_formatting_provider: OneOf::Left(true).into(),
+            code_action_provider: CodeActionProviderCapability::Options(CodeActionOptions {
+                code_action_kinds: Some(vec![CodeActionKind::SOURCE_FIX_ALL]),
+                ..Default::default()
+            })
+            .into(),
             ..Default::default()
         },
         server_info: None,
@@ -91,6 +100,13 @@ impl Wasm {
         let edits = self.0.format(uri);
         serde_wasm_bindgen::to_value(&edits).unwrap()
     }
+
+    #[wasm_bindgen(js_name = codeAction)]
+    pub fn code_action(&mut self, params: JsValue) -> JsValue {
+        let params = serde_wasm_bindgen::from_value(params).unwrap();
+        let actions = self.0.code_action(params);
+        serde_wasm_bindgen::to_value(&actions).unwrap()
+    }
 }
 
 impl LanguageServer {
@@ -113,6 +129,12 @@ impl LanguageServer {
                 let edits = self.format(uri);
                 Some(Response::new_ok(id, edits))
             }
+            CodeActionRequest::METHOD => {
+                let params: CodeActionParams = serde_json::from_value(params).unwrap();
+
+                let actions = self.code_action(params);
+                Some(Response::new_ok(id, actions))
+            }
             _ => None,
         }
     }
@@ -138,6 +160,43 @@ impl LanguageServer {
         result
     }
 
+    /// Offers to apply every available fix as a source action. The fixes come from formatting
+    /// the whole document, so no quick fix is offered for a single diagnostic.
+    fn code_action(&mut self, params: CodeActionParams) -> Vec<CodeActionOrCommand> {
+        let CodeActionParams {
+            text_document: TextDocumentIdentifier { uri },
+            context: CodeActionContext { only, .. },
+            ..
+        } = params;
+
+        // `only` holds kinds like `source`, which include their sub-kinds.
+        let kind = CodeActionKind::SOURCE_FIX_ALL;
+        let requested = only.as_ref().is_none_or(|only| {
+            only.iter().any(|it| {
+                kind.as_str() == it.as_str()
+                    || kind.as_str().starts_with(&format!("{}.", it.as_str()))
+            })
+        });
+        if !requested || !self.documents.contains_key(&uri) {
+            return Vec::new();
+        }
+
+        let edits = self.format(uri.clone());
+        if edits
+            .iter()
+            .all(|edit| edit.new_text == self.documents[&uri])
+        {
+            return Vec::new();
+        }
+
+        vec![CodeActionOrCommand::CodeAction(CodeAction {
+            title: "Fix all auto-fixable sqruff violations".into(),
+            kind: Some(kind),
+            edit: Some(WorkspaceEdit::new(HashMap::from([(uri, edits)]))),
+            ..Default::default()
+        })]
+    }
+
     pub fn on_notification(&mut self, method: &str, params: Value) {
         match method {
             DidOpenTextDocument::METHOD => {
@@ -204,7 +263,7 @@ impl LanguageServer {
                     .rule
                     .map(|rule| NumberOrString::String(rule.code.to_string()));
 
-                Diagnostic::new(
+                let mut diagnostic = Diagnostic::new(
                     range,
                     DiagnosticSeverity::WARNING.into(),
                     code,
@@ -212,7 +271,9 @@ impl LanguageServer {
                     violation.description,
                     None,
                     None,
-                )
+                );
+                diagnostic.data = Some(json!({ "fixable": violation.fixable }));
+                diagnostic
             })
             .collect();
 
@@ -310,3 +371,107 @@ where
         params: serde_json::to_value(&params).unwrap(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn open(source: &str) -> (LanguageServer, Uri, Vec<Diagnostic>) {
+        let published = Rc::new(RefCell::new(Vec::new()));
+        let mut server = LanguageServer::new({
+            let published = published.clone();
+            move |params: PublishDiagnosticsParams| {
+                published.borrow_mut().extend(params.diagnostics);
+            }
+        });
+        *server.linter.config_mut() =
+            FluffConfig::from_source("[sqruff]\ndialect = ansi\nrules = AL04,LT01\n", None);
+
+        let uri: Uri = "file:///query.sql".parse().unwrap();
+        server.on_notification(
+            DidOpenTextDocument::METHOD,
+            json!({
+                "textDocument": {
+                    "uri": uri,
+                    "languageId": "sql",
+                    "version": 0,
+                    "text": source,
+                }
+            }),
+        );
+
+        let diagnostics = published.take();
+        (server, uri, diagnostics)
+    }
+
+    fn params(
+        uri: &Uri,
+        diagnostics: Vec<Diagnostic>,
+        only: Option<Vec<CodeActionKind>>,
+    ) -> CodeActionParams {
+        serde_json::from_value(json!({
+            "textDocument": { "uri": uri },
+            "range": {
+                "start": { "line": 0, "character": 0 },
+                "end": { "line": 0, "character": 0 },
+            },
+            "context": { "diagnostics": diagnostics, "only": only },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_code_action_fixable() {
+        let (mut server, uri, diagnostics) = open("SELECT 1  AS a\n");
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].data, Some(json!({ "fixable": true })));
+
+        let actions = server.code_action(params(&uri, diagnostics, None));
+        let [CodeActionOrCommand::CodeAction(action)] = actions.as_slice() else {
+            panic!("expected a single code action, got {actions:?}");
+        };
+        assert_eq!(action.kind, Some(CodeActionKind::SOURCE_FIX_ALL));
+        assert_eq!(action.diagnostics, None);
+
+        let changes = action.edit.as_ref().unwrap().changes.as_ref().unwrap();
+        assert_eq!(changes[&uri][0].new_text, "SELECT 1 AS a\n");
+    }
+
+    #[test]
+    fn test_code_action_only() {
+        let (mut server, uri, diagnostics) = open("SELECT 1  AS a\n");
+
+        let source = server.code_action(params(
+            &uri,
+            diagnostics.clone(),
+            Some(vec![CodeActionKind::SOURCE]),
+        ));
+        assert_eq!(source.len(), 1);
+
+        let quickfix = server.code_action(params(
+            &uri,
+            diagnostics,
+            Some(vec![CodeActionKind::QUICKFIX]),
+        ));
+        assert!(quickfix.is_empty());
+    }
+
+    #[test]
+    fn test_code_action_unfixable() {
+        let (mut server, uri, diagnostics) = open("SELECT t.a FROM foo AS t, bar AS t\n");
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code,
+            Some(NumberOrString::String("AL04".into()))
+        );
+        assert_eq!(diagnostics[0].data, Some(json!({ "fixable": false })));
+
+        let actions = server.code_action(params(&uri, diagnostics, None));
+        assert!(actions.is_empty());
+    }
+}
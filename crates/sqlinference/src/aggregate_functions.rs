@@ -23,6 +23,8 @@ pub fn aggregate_function_behaviour(
         | DialectKind::Snowflake
         | DialectKind::Duckdb
         | DialectKind::Clickhouse
+        | DialectKind::Mysql
+        | DialectKind::Tsql
         | DialectKind::Postgres => HashMap::from([
             (
                 Operation::Min,
@@ -7,6 +7,7 @@ import {
   BrowserMessageWriter,
   PublishDiagnosticsParams,
   DocumentFormattingParams,
+  CodeActionParams,
 } from "vscode-languageserver/browser";
 
 sqruffInit(sqruffWasmData).then(() => {
@@ -44,6 +45,10 @@ sqruffInit(sqruffWasmData).then(() => {
     },
   );
 
+  connection.onCodeAction((params: CodeActionParams) => {
+    return lsp.codeAction(params);
+  });
+
   connection.onRequest("changeConfig", () => {
     updateConfig();
   });